@@ -0,0 +1,87 @@
+//! Account lists of the create instructions, for programs that CPI into this
+//! one. Unlike the `client` builders these don't need any feature.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use solana_program::{
+    instruction::AccountMeta, pubkey::Pubkey, system_program, sysvar::instructions,
+};
+
+use crate::{
+    find_blacklist_account, find_config_account, find_creation_quota_account,
+    find_fee_stats_account, find_info_account, find_operator_account, find_receipt_account,
+};
+
+/// Accounts of `CreateInfoV2`, `CreateInfoIdempotent`, `PreviewCreateInfo`
+/// and `CreateInfoPrepaid`. The PDAs are derived from these keys.
+pub struct CreateInfoAccounts {
+    pub tenant: Pubkey,
+    pub payer: Pubkey,
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    /// `Config::create_authority` of the tenant; signs the outer transaction.
+    pub authority: Pubkey,
+    /// The current `Config::fee_receiver`.
+    pub fee_receiver: Pubkey,
+    /// Adds the instructions sysvar that `CreateInfoPrepaid` reads.
+    pub prepaid: bool,
+    /// Needed while the config prices the fee in USD.
+    pub price_update: Option<Pubkey>,
+    /// `CreateInfoArgs::origin`, added as a signer along with its operator
+    /// PDA.
+    pub origin: Option<Pubkey>,
+    /// The origin's registered `Operator::payout`; required once the origin
+    /// is a registered operator.
+    pub operator_payout: Option<Pubkey>,
+}
+
+impl CreateInfoAccounts {
+    /// The metas in the order the program reads them, with their signer and
+    /// writable flags.
+    pub fn to_account_metas(&self, program_id: &Pubkey) -> Vec<AccountMeta> {
+        let tenant = &self.tenant;
+        let mut metas = vec![
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.creator, true),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(find_info_account(tenant, &self.mint, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(self.fee_receiver, false),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(
+                find_blacklist_account(tenant, &self.mint, program_id).0,
+                false,
+            ),
+            AccountMeta::new(
+                find_fee_stats_account(tenant, &self.payer, program_id).0,
+                false,
+            ),
+            AccountMeta::new(
+                find_creation_quota_account(tenant, &self.creator, program_id).0,
+                false,
+            ),
+            AccountMeta::new(
+                find_receipt_account(tenant, &self.payer, &self.mint, program_id).0,
+                false,
+            ),
+        ];
+        if self.prepaid {
+            metas.push(AccountMeta::new_readonly(instructions::ID, false));
+        }
+        if let Some(price_update) = self.price_update {
+            metas.push(AccountMeta::new_readonly(price_update, false));
+        }
+        if let Some(origin) = self.origin {
+            metas.push(AccountMeta::new_readonly(origin, true));
+            metas.push(AccountMeta::new_readonly(
+                find_operator_account(tenant, &origin, program_id).0,
+                false,
+            ));
+        }
+        if let Some(payout) = self.operator_payout {
+            metas.push(AccountMeta::new(payout, false));
+        }
+        metas
+    }
+}
@@ -0,0 +1,1151 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use borsh::BorshDeserialize;
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, extend_lookup_table,
+};
+use solana_program::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction as SolanaInstruction},
+    message::{AddressLookupTableAccount, CompileError, v0},
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::accounts::CreateInfoAccounts;
+use crate::{
+    AUTHORITY, AdminAction, AllowList, CreateInfoArgs, DescriptionFormat, GLOBAL_TENANT,
+    ImagesMetadata, Instruction, LaunchTimeline, Link, LinkStatus, LoyaltyTier, UsdFee,
+    find_blacklist_account, find_challenge_account, find_config_account,
+    find_creation_quota_account, find_endorsement_account, find_info_account,
+    find_link_attestations_account, find_listing_bond_account, find_operator_account,
+    find_pending_action_account, find_receipt_account, find_sponsorship_vault,
+};
+
+/// Accounts that are the same for every call into the program and can live in
+/// an address lookup table. Signers (payer, creator, authority) are left out
+/// because lookup tables can't supply signatures.
+///
+/// `fee_receiver` is the current `Config::fee_receiver`; extend the table again
+/// after a rotation.
+pub fn static_lookup_addresses(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    fee_receiver: &Pubkey,
+) -> Vec<Pubkey> {
+    vec![
+        *program_id,
+        system_program::id(),
+        *fee_receiver,
+        find_config_account(tenant, program_id).0,
+        find_sponsorship_vault(tenant, program_id).0,
+    ]
+}
+
+/// Returns the create instruction and the address of the new lookup table.
+pub fn create_static_lookup_table(
+    table_authority: &Pubkey,
+    payer: &Pubkey,
+    recent_slot: u64,
+) -> (SolanaInstruction, Pubkey) {
+    create_lookup_table(*table_authority, *payer, recent_slot)
+}
+
+pub fn extend_static_lookup_table(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    fee_receiver: &Pubkey,
+    lookup_table: &Pubkey,
+    table_authority: &Pubkey,
+    payer: &Pubkey,
+) -> SolanaInstruction {
+    extend_lookup_table(
+        *lookup_table,
+        *table_authority,
+        Some(*payer),
+        static_lookup_addresses(program_id, tenant, fee_receiver),
+    )
+}
+
+/// Compiles `instructions` into a `v0` message that resolves the program's
+/// static accounts through `lookup_table`.
+pub fn compile_v0_message(
+    payer: &Pubkey,
+    instructions: &[SolanaInstruction],
+    lookup_table: &AddressLookupTableAccount,
+    recent_blockhash: Hash,
+) -> Result<v0::Message, CompileError> {
+    v0::Message::try_compile(
+        payer,
+        instructions,
+        core::slice::from_ref(lookup_table),
+        recent_blockhash,
+    )
+}
+
+/// Legacy `CreateInfo`; the description is recorded as plain text and the
+/// payer as creator. Passes the tenant's config, so its cutover applies.
+#[allow(clippy::too_many_arguments)]
+pub fn create_info(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    description: String,
+    links: Vec<Link>,
+    icon_uri: String,
+    header_uri: String,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::CreateInfo {
+            description,
+            links,
+            icon_uri,
+            header_uri,
+        },
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(find_info_account(tenant, mint, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(*fee_receiver, false),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_info_v2(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    args: CreateInfoArgs,
+) -> SolanaInstruction {
+    let origin = args.origin;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::CreateInfoV2(args),
+        create_info_account_metas(
+            program_id,
+            tenant,
+            payer,
+            creator,
+            mint,
+            fee_receiver,
+            authority,
+            origin,
+        ),
+    )
+}
+
+/// Put it right after a `system_instruction::transfer` of the fee to
+/// `fee_receiver`; the payer then only funds rent.
+#[allow(clippy::too_many_arguments)]
+pub fn create_info_prepaid(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    args: CreateInfoArgs,
+) -> SolanaInstruction {
+    let origin = args.origin;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::CreateInfoPrepaid(args),
+        CreateInfoAccounts {
+            tenant: *tenant,
+            payer: *payer,
+            creator: *creator,
+            mint: *mint,
+            authority: *authority,
+            fee_receiver: *fee_receiver,
+            prepaid: true,
+            price_update: None,
+            origin,
+            operator_payout: None,
+        }
+        .to_account_metas(program_id),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_info_idempotent(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    args: CreateInfoArgs,
+) -> SolanaInstruction {
+    let origin = args.origin;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::CreateInfoIdempotent(args),
+        create_info_account_metas(
+            program_id,
+            tenant,
+            payer,
+            creator,
+            mint,
+            fee_receiver,
+            authority,
+            origin,
+        ),
+    )
+}
+
+/// Same accounts as `create_info_v2`; simulate it and decode the return data
+/// as a `CreateQuote`.
+#[allow(clippy::too_many_arguments)]
+pub fn preview_create_info(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    args: CreateInfoArgs,
+) -> SolanaInstruction {
+    let origin = args.origin;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::PreviewCreateInfo(args),
+        create_info_account_metas(
+            program_id,
+            tenant,
+            payer,
+            creator,
+            mint,
+            fee_receiver,
+            authority,
+            origin,
+        ),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_info_account_metas(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    origin: Option<Pubkey>,
+) -> Vec<AccountMeta> {
+    CreateInfoAccounts {
+        tenant: *tenant,
+        payer: *payer,
+        creator: *creator,
+        mint: *mint,
+        authority: *authority,
+        fee_receiver: *fee_receiver,
+        prepaid: false,
+        price_update: None,
+        origin,
+        operator_payout: None,
+    }
+    .to_account_metas(program_id)
+}
+
+pub fn create_info_sponsored(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    fee_receiver: &Pubkey,
+    authority: &Pubkey,
+    args: CreateInfoArgs,
+) -> SolanaInstruction {
+    let (info_account, _) = find_info_account(tenant, mint, program_id);
+    let origin = args.origin;
+    let mut metas = vec![
+        AccountMeta::new_readonly(*creator, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(info_account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*fee_receiver, false),
+        AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        AccountMeta::new(find_sponsorship_vault(tenant, program_id).0, false),
+        AccountMeta::new_readonly(find_blacklist_account(tenant, mint, program_id).0, false),
+        AccountMeta::new(
+            find_creation_quota_account(tenant, creator, program_id).0,
+            false,
+        ),
+    ];
+    if let Some(origin) = origin {
+        metas.push(AccountMeta::new_readonly(origin, true));
+    }
+    SolanaInstruction::new_with_borsh(*program_id, &Instruction::CreateInfoSponsored(args), metas)
+}
+
+pub fn init_config(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    sponsorship_daily_cap: u64,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::InitConfig {
+            sponsorship_daily_cap,
+        },
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(AUTHORITY, true),
+            AccountMeta::new(find_config_account(&GLOBAL_TENANT, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn set_sponsorship_cap(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    daily_cap: u64,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetSponsorshipCap { daily_cap },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn fund_sponsorship(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    funder: &Pubkey,
+    amount: u64,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::FundSponsorship { amount },
+        vec![
+            AccountMeta::new(*funder, true),
+            AccountMeta::new(find_sponsorship_vault(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn set_max_account_size(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    max_account_size: u32,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetMaxAccountSize { max_account_size },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn set_allowed_description_formats(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    formats: Vec<DescriptionFormat>,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetAllowedDescriptionFormats { formats },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn update_timeline(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    timeline: LaunchTimeline,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::UpdateTimeline { timeline },
+        vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(find_info_account(tenant, mint, program_id).0, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn add_to_allow_list(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    list: AllowList,
+    key: Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::AddToAllowList { list, key },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn remove_from_allow_list(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    list: AllowList,
+    key: Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::RemoveFromAllowList { list, key },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn add_to_blacklist(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::AddToBlacklist,
+        blacklist_account_metas(program_id, tenant, admin, mint),
+    )
+}
+
+pub fn remove_from_blacklist(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::RemoveFromBlacklist,
+        blacklist_account_metas(program_id, tenant, admin, mint),
+    )
+}
+
+pub fn set_operator(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    operator: &Pubkey,
+    payout: &Pubkey,
+    share_bps: u16,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetOperator {
+            payout: *payout,
+            share_bps,
+        },
+        operator_account_metas(program_id, tenant, admin, operator),
+    )
+}
+
+pub fn remove_operator(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    operator: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::RemoveOperator,
+        operator_account_metas(program_id, tenant, admin, operator),
+    )
+}
+
+fn operator_account_metas(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    operator: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(find_operator_account(tenant, operator, program_id).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]
+}
+
+fn blacklist_account_metas(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(find_blacklist_account(tenant, mint, program_id).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]
+}
+
+pub fn propose_fee_receiver(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    candidate: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ProposeFeeReceiver {
+            candidate: *candidate,
+        },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn accept_fee_receiver(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    candidate: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::AcceptFeeReceiver,
+        vec![
+            AccountMeta::new(*candidate, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `action_id` is the config's current `next_action_id`.
+pub fn queue_admin_action(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    action_id: u64,
+    action: AdminAction,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::QueueAdminAction { action },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(
+                find_pending_action_account(tenant, action_id, program_id).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn execute_admin_action(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    executor: &Pubkey,
+    action_id: u64,
+    queued_by: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ExecuteAdminAction,
+        vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(
+                find_pending_action_account(tenant, action_id, program_id).0,
+                false,
+            ),
+            AccountMeta::new(*queued_by, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn cancel_admin_action(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    action_id: u64,
+    queued_by: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::CancelAdminAction,
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(
+                find_pending_action_account(tenant, action_id, program_id).0,
+                false,
+            ),
+            AccountMeta::new(*queued_by, false),
+        ],
+    )
+}
+
+pub fn set_loyalty_tiers(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    tiers: Vec<LoyaltyTier>,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetLoyaltyTiers { tiers },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// Appends the Pyth price update account that create instructions need while
+/// the config prices the fee in USD.
+pub fn with_price_update(
+    mut instruction: SolanaInstruction,
+    price_update: &Pubkey,
+) -> SolanaInstruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*price_update, false));
+    instruction
+}
+
+pub fn set_usd_fee(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    usd_fee: Option<UsdFee>,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetUsdFee { usd_fee },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `info_accounts` and `flags` are paired up in order.
+pub fn set_verified_batch(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    info_accounts: &[Pubkey],
+    flags: Vec<bool>,
+) -> SolanaInstruction {
+    let mut accounts = vec![
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(
+        info_accounts
+            .iter()
+            .map(|info_account| AccountMeta::new(*info_account, false)),
+    );
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetVerifiedBatch { flags },
+        accounts,
+    )
+}
+
+/// Creates `tenant`'s config; both the tenant and the authority sign.
+pub fn init_tenant_config(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    tenant: &Pubkey,
+    sponsorship_daily_cap: u64,
+    fee_receiver: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::InitTenantConfig {
+            sponsorship_daily_cap,
+            fee_receiver: *fee_receiver,
+        },
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(AUTHORITY, true),
+            AccountMeta::new_readonly(*tenant, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn set_creator_daily_quota(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    quota: u32,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetCreatorDailyQuota { quota },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn set_link_attester(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    attester: Option<Pubkey>,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetLinkAttester { attester },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// Simulate it and decode the return data as a `Vec<ConfigViolation>`.
+pub fn validate_config(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    action: Option<AdminAction>,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ValidateConfig { action },
+        vec![AccountMeta::new_readonly(
+            find_config_account(tenant, program_id).0,
+            false,
+        )],
+    )
+}
+
+/// Simulate it and decode the return data as `Stats`.
+pub fn get_stats(program_id: &Pubkey, tenant: &Pubkey) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::GetStats,
+        vec![
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(find_sponsorship_vault(tenant, program_id).0, false),
+        ],
+    )
+}
+
+/// Simulate it and decode the return data as `MintStats`.
+pub fn get_mint_stats(program_id: &Pubkey, tenant: &Pubkey, mint: &Pubkey) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::GetMintStats,
+        vec![
+            AccountMeta::new_readonly(info_account, false),
+            AccountMeta::new_readonly(
+                find_listing_bond_account(&info_account, program_id).0,
+                false,
+            ),
+        ],
+    )
+}
+
+/// Simulate it and decode the return data as a Borsh `u32`.
+pub fn get_api_version(program_id: &Pubkey) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(*program_id, &Instruction::GetApiVersion, vec![])
+}
+
+/// Wraps an instruction built by this module in `Instruction::Versioned`, so
+/// it fails on builds older than `min_api_version`. Panics if the data isn't
+/// one of this program's instructions; the program refuses a `Versioned`
+/// one.
+pub fn versioned(min_api_version: u32, instruction: SolanaInstruction) -> SolanaInstruction {
+    let inner =
+        Instruction::try_from_slice(&instruction.data).expect("not an instruction of this program");
+    SolanaInstruction::new_with_borsh(
+        instruction.program_id,
+        &Instruction::Versioned {
+            min_api_version,
+            instruction: Box::new(inner),
+        },
+        instruction.accounts,
+    )
+}
+
+pub fn set_v1_cutover_slot(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    slot: Option<u64>,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetV1CutoverSlot { slot },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn attest_link(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    attester: &Pubkey,
+    mint: &Pubkey,
+    index: u32,
+    status: LinkStatus,
+) -> SolanaInstruction {
+    let (info_account, _) = find_info_account(tenant, mint, program_id);
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::AttestLink { index, status },
+        vec![
+            AccountMeta::new(*attester, true),
+            AccountMeta::new_readonly(info_account, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(
+                find_link_attestations_account(&info_account, program_id).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn trim_info_account(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::TrimInfoAccount,
+        vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(find_info_account(tenant, mint, program_id).0, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn update_images(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    icon_uri: String,
+    header_uri: String,
+    image_metadata: ImagesMetadata,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::UpdateImages {
+            icon_uri,
+            header_uri,
+            image_metadata,
+        },
+        vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(find_info_account(tenant, mint, program_id).0, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `fee_receiver` co-signs: the refund is paid from its balance.
+pub fn refund_receipt(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    fee_receiver: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::RefundReceipt,
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(*fee_receiver, true),
+            AccountMeta::new(*payer, false),
+            AccountMeta::new(
+                find_receipt_account(tenant, payer, mint, program_id).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// Paid out of the tenant's sponsorship vault.
+pub fn refund_fee(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::RefundFee { amount },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(find_sponsorship_vault(tenant, program_id).0, false),
+            AccountMeta::new(*payer, false),
+            AccountMeta::new(
+                find_receipt_account(tenant, payer, mint, program_id).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn emit_snapshot(program_id: &Pubkey, tenant: &Pubkey, mint: &Pubkey) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::EmitSnapshot,
+        vec![AccountMeta::new_readonly(
+            find_info_account(tenant, mint, program_id).0,
+            false,
+        )],
+    )
+}
+
+pub fn endorse(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    endorser: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::Endorse,
+        vec![
+            AccountMeta::new(*endorser, true),
+            AccountMeta::new(info_account, false),
+            AccountMeta::new(
+                find_endorsement_account(&info_account, endorser, program_id).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn set_verification_dispute(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    delay: i64,
+    bond: u64,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetVerificationDispute { delay, bond },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn challenge_verification(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    challenger: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ChallengeVerification,
+        vec![
+            AccountMeta::new(*challenger, true),
+            AccountMeta::new(info_account, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(find_challenge_account(&info_account, program_id).0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn resolve_challenge(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+    challenger: &Pubkey,
+    fee_receiver: &Pubkey,
+    upheld: bool,
+) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ResolveChallenge { upheld },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(info_account, false),
+            AccountMeta::new(find_challenge_account(&info_account, program_id).0, false),
+            AccountMeta::new(*challenger, false),
+            AccountMeta::new(*fee_receiver, false),
+        ],
+    )
+}
+
+pub fn set_listing_bond_lock(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    lock: i64,
+) -> SolanaInstruction {
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::SetListingBondLock { lock },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(find_config_account(tenant, program_id).0, false),
+        ],
+    )
+}
+
+pub fn post_listing_bond(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::PostListingBond { amount },
+        vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(info_account, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(
+                find_listing_bond_account(&info_account, program_id).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn reclaim_bond(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ReclaimBond,
+        vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(info_account, false),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new(
+                find_listing_bond_account(&info_account, program_id).0,
+                false,
+            ),
+        ],
+    )
+}
+
+pub fn forfeit_bond(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+    creator: &Pubkey,
+    fee_receiver: &Pubkey,
+) -> SolanaInstruction {
+    let info_account = find_info_account(tenant, mint, program_id).0;
+    SolanaInstruction::new_with_borsh(
+        *program_id,
+        &Instruction::ForfeitBond,
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(find_config_account(tenant, program_id).0, false),
+            AccountMeta::new_readonly(info_account, false),
+            AccountMeta::new(
+                find_listing_bond_account(&info_account, program_id).0,
+                false,
+            ),
+            AccountMeta::new(*creator, false),
+            AccountMeta::new(*fee_receiver, false),
+        ],
+    )
+}
+
+/// Position of `account` in `instruction`'s account list, for translating an
+/// `Event::ValidationFailed` from a failed simulation into the offending
+/// account index.
+pub fn account_index(instruction: &SolanaInstruction, account: &Pubkey) -> Option<usize> {
+    instruction
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == *account)
+}
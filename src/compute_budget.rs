@@ -0,0 +1,55 @@
+//! Compute unit limits per instruction, for clients building a
+//! `ComputeBudgetInstruction::set_compute_unit_limit` alongside each call.
+//! The `bench` feature's `compute_units` test fails if an instruction
+//! consumes more than its limit here.
+
+pub const CREATE_INFO: u32 = 60_000;
+pub const CREATE_INFO_V2: u32 = 60_000;
+pub const CREATE_INFO_SPONSORED: u32 = 70_000;
+pub const INIT_CONFIG: u32 = 30_000;
+pub const SET_SPONSORSHIP_CAP: u32 = 10_000;
+pub const FUND_SPONSORSHIP: u32 = 10_000;
+pub const SET_MAX_ACCOUNT_SIZE: u32 = 10_000;
+pub const SET_ALLOWED_DESCRIPTION_FORMATS: u32 = 10_000;
+pub const UPDATE_TIMELINE: u32 = 20_000;
+pub const ADD_TO_ALLOW_LIST: u32 = 20_000;
+pub const REMOVE_FROM_ALLOW_LIST: u32 = 20_000;
+pub const ADD_TO_BLACKLIST: u32 = 30_000;
+pub const REMOVE_FROM_BLACKLIST: u32 = 15_000;
+pub const PROPOSE_FEE_RECEIVER: u32 = 20_000;
+pub const ACCEPT_FEE_RECEIVER: u32 = 20_000;
+pub const QUEUE_ADMIN_ACTION: u32 = 30_000;
+pub const EXECUTE_ADMIN_ACTION: u32 = 30_000;
+pub const CANCEL_ADMIN_ACTION: u32 = 15_000;
+pub const SET_LOYALTY_TIERS: u32 = 20_000;
+pub const SET_USD_FEE: u32 = 20_000;
+pub const PREVIEW_CREATE_INFO: u32 = 40_000;
+pub const CREATE_INFO_IDEMPOTENT: u32 = 60_000;
+/// Per info account in the batch.
+pub const SET_VERIFIED_BATCH_PER_ACCOUNT: u32 = 15_000;
+pub const INIT_TENANT_CONFIG: u32 = 30_000;
+pub const SET_CREATOR_DAILY_QUOTA: u32 = 10_000;
+pub const SET_LINK_ATTESTER: u32 = 20_000;
+pub const ATTEST_LINK: u32 = 40_000;
+pub const UPDATE_IMAGES: u32 = 30_000;
+/// Includes the preceding fee transfer.
+pub const CREATE_INFO_PREPAID: u32 = 60_000;
+pub const TRIM_INFO_ACCOUNT: u32 = 20_000;
+pub const REFUND_RECEIPT: u32 = 20_000;
+pub const REFUND_FEE: u32 = 20_000;
+pub const EMIT_SNAPSHOT: u32 = 20_000;
+pub const SET_V1_CUTOVER_SLOT: u32 = 20_000;
+pub const ENDORSE: u32 = 20_000;
+pub const SET_VERIFICATION_DISPUTE: u32 = 10_000;
+pub const CHALLENGE_VERIFICATION: u32 = 30_000;
+pub const RESOLVE_CHALLENGE: u32 = 20_000;
+pub const SET_LISTING_BOND_LOCK: u32 = 10_000;
+pub const POST_LISTING_BOND: u32 = 30_000;
+pub const RECLAIM_BOND: u32 = 20_000;
+pub const FORFEIT_BOND: u32 = 20_000;
+pub const SET_OPERATOR: u32 = 20_000;
+pub const REMOVE_OPERATOR: u32 = 15_000;
+pub const VALIDATE_CONFIG: u32 = 15_000;
+pub const GET_STATS: u32 = 10_000;
+pub const GET_MINT_STATS: u32 = 15_000;
+pub const GET_API_VERSION: u32 = 5_000;
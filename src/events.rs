@@ -0,0 +1,170 @@
+//! Structured events emitted through `sol_log_data` so indexers can decode
+//! them from transaction logs (`Program data: <base64>`) with Borsh.
+
+use alloc::vec::Vec;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{AdminAction, LinkStatus, TokenInfo, TokenInfoV2};
+
+/// Bits of `Event::InfoUpdated::changed_fields`.
+pub const CHANGED_DESCRIPTION: u32 = 1 << 0;
+pub const CHANGED_DESCRIPTION_FORMAT: u32 = 1 << 1;
+pub const CHANGED_LINKS: u32 = 1 << 2;
+pub const CHANGED_IMAGES: u32 = 1 << 3;
+pub const CHANGED_TIMELINE: u32 = 1 << 4;
+pub const CHANGED_VERIFIED: u32 = 1 << 5;
+
+// Built once per instruction and serialized right away; boxing the snapshot
+// would only complicate matching.
+#[allow(clippy::large_enum_variant)]
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum Event {
+    FeeReceiverProposed {
+        current: Pubkey,
+        candidate: Pubkey,
+        executable_at: i64,
+    },
+    FeeReceiverAccepted {
+        previous: Pubkey,
+        fee_receiver: Pubkey,
+    },
+    AdminActionQueued {
+        id: u64,
+        action: AdminAction,
+        executable_at: i64,
+    },
+    AdminActionExecuted {
+        id: u64,
+    },
+    AdminActionCancelled {
+        id: u64,
+    },
+    /// Hashes are sha256 of the Borsh-encoded `TokenInfo` before and after the
+    /// write, without the account header.
+    InfoUpdated {
+        mint: Pubkey,
+        changed_fields: u32,
+        old_hash: [u8; 32],
+        new_hash: [u8; 32],
+    },
+    LinkAttested {
+        mint: Pubkey,
+        index: u32,
+        status: LinkStatus,
+        checked_at: i64,
+    },
+    /// Also returned as `CreatedInfo` return data.
+    InfoCreated {
+        tenant: Pubkey,
+        mint: Pubkey,
+        creator: Pubkey,
+        info_account: Pubkey,
+        bump: u8,
+    },
+    FeeRefunded {
+        payer: Pubkey,
+        mint: Pubkey,
+        amount: u64,
+    },
+    /// The full record as of `slot`, from `EmitSnapshot`.
+    InfoSnapshot {
+        info_account: Pubkey,
+        slot: u64,
+        info: TokenInfo,
+    },
+    Endorsed {
+        mint: Pubkey,
+        endorser: Pubkey,
+        endorsements: u64,
+    },
+    VerificationChallenged {
+        mint: Pubkey,
+        challenger: Pubkey,
+        bond: u64,
+    },
+    ChallengeResolved {
+        mint: Pubkey,
+        upheld: bool,
+    },
+    ListingBondPosted {
+        mint: Pubkey,
+        amount: u64,
+    },
+    /// Reclaimed by the creator, or forfeited to the fee receiver.
+    ListingBondReleased {
+        mint: Pubkey,
+        amount: u64,
+        forfeited: bool,
+    },
+    /// Logged by the account checks in `validation` when one fails. `code` is
+    /// the returned `ProgramError` as a `u64`; `client::account_index` maps
+    /// `account` back to its position in the failed instruction.
+    ValidationFailed {
+        code: u64,
+        account: Pubkey,
+    },
+}
+
+pub fn emit(event: &Event) -> Result<(), ProgramError> {
+    let mut data = Vec::with_capacity(128);
+    event.serialize(&mut data)?;
+    sol_log_data(&[&data]);
+    Ok(())
+}
+
+/// Content fields that differ between two versions of a record. Timestamps
+/// aren't tracked since every update bumps them.
+pub fn changed_fields(old: &TokenInfoV2, new: &TokenInfoV2) -> u32 {
+    let mut changed = 0;
+    if old.description != new.description {
+        changed |= CHANGED_DESCRIPTION;
+    }
+    if old.description_format != new.description_format {
+        changed |= CHANGED_DESCRIPTION_FORMAT;
+    }
+    if old.links != new.links {
+        changed |= CHANGED_LINKS;
+    }
+    if old.images != new.images || old.image_metadata != new.image_metadata {
+        changed |= CHANGED_IMAGES;
+    }
+    if old.timeline != new.timeline {
+        changed |= CHANGED_TIMELINE;
+    }
+    if old.verified != new.verified {
+        changed |= CHANGED_VERIFIED;
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DescriptionFormat, Link, fixtures};
+
+    #[test]
+    fn unchanged_record_has_no_changed_fields() {
+        let info = fixtures::sample_info(&Pubkey::new_unique(), &Pubkey::new_unique());
+        assert_eq!(changed_fields(&info, &info.clone()), 0);
+    }
+
+    #[test]
+    fn flags_each_changed_field() {
+        let old = fixtures::sample_info(&Pubkey::new_unique(), &Pubkey::new_unique());
+        let mut new = old.clone();
+        new.description_format = DescriptionFormat::Markdown;
+        new.links.push(Link {
+            label: "Docs".into(),
+            url: "https://example.com/docs".into(),
+        });
+        new.verified = true;
+        // Timestamps aren't content.
+        new.update_timestamp += 1;
+
+        assert_eq!(
+            changed_fields(&old, &new),
+            CHANGED_DESCRIPTION_FORMAT | CHANGED_LINKS | CHANGED_VERIFIED
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Account data laid out exactly as the program writes it, for wallets and
+//! indexers to test their decoders against. Enabled by the `fixtures`
+//! feature.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    AccountHeader, BLACKLIST_MAGIC_BYTE, BLACKLIST_VERSION, BlacklistEntry, CHALLENGE_MAGIC_BYTE,
+    CHALLENGE_VERSION, CONFIG_MAGIC_BYTE, CONFIG_VERSION, CREATION_QUOTA_MAGIC_BYTE,
+    CREATION_QUOTA_VERSION, Config, CreationQuota, DATA_VERSION, DescriptionFormat,
+    ENDORSEMENT_MAGIC_BYTE, ENDORSEMENT_VERSION, FEE_STATS_MAGIC_BYTE, FEE_STATS_VERSION, FeeStats,
+    Images, ImagesMetadata, LEGACY_DATA_VERSION, LINK_ATTESTATIONS_MAGIC_BYTE,
+    LINK_ATTESTATIONS_VERSION, LISTING_BOND_MAGIC_BYTE, LISTING_BOND_VERSION, LaunchTimeline, Link,
+    LinkAttestations, ListingBond, MAGIC_BYTE, NAME_LEN, OPERATOR_MAGIC_BYTE, OPERATOR_VERSION,
+    Operator, PENDING_ACTION_MAGIC_BYTE, PENDING_ACTION_VERSION, PendingAction, RECEIPT_MAGIC_BYTE,
+    RECEIPT_VERSION, Receipt, SYMBOL_LEN, TokenInfo, TokenInfoV1, TokenInfoV2,
+    VerificationChallenge, search_key,
+};
+
+/// A record type and the header the program stores it under.
+pub trait Record: BorshSerialize {
+    const MAGIC: u8;
+    const VERSION: u8;
+}
+
+macro_rules! record {
+    ($($record:ty => $magic:ident, $version:ident;)*) => {
+        $(impl Record for $record {
+            const MAGIC: u8 = $magic;
+            const VERSION: u8 = $version;
+        })*
+    };
+}
+
+record! {
+    TokenInfo => MAGIC_BYTE, DATA_VERSION;
+    Config => CONFIG_MAGIC_BYTE, CONFIG_VERSION;
+    BlacklistEntry => BLACKLIST_MAGIC_BYTE, BLACKLIST_VERSION;
+    PendingAction => PENDING_ACTION_MAGIC_BYTE, PENDING_ACTION_VERSION;
+    FeeStats => FEE_STATS_MAGIC_BYTE, FEE_STATS_VERSION;
+    CreationQuota => CREATION_QUOTA_MAGIC_BYTE, CREATION_QUOTA_VERSION;
+    LinkAttestations => LINK_ATTESTATIONS_MAGIC_BYTE, LINK_ATTESTATIONS_VERSION;
+    Receipt => RECEIPT_MAGIC_BYTE, RECEIPT_VERSION;
+    VerificationChallenge => CHALLENGE_MAGIC_BYTE, CHALLENGE_VERSION;
+    ListingBond => LISTING_BOND_MAGIC_BYTE, LISTING_BOND_VERSION;
+    Operator => OPERATOR_MAGIC_BYTE, OPERATOR_VERSION;
+}
+
+/// The data of an account holding `record`, last written at
+/// `written_at_slot`.
+pub fn account_data<T: Record>(record: &T, written_at_slot: u64) -> Vec<u8> {
+    let header = AccountHeader {
+        magic: T::MAGIC,
+        schema_version: T::VERSION,
+        written_at_slot,
+    };
+    // Writing into a `Vec` can't fail.
+    let mut data = borsh::to_vec(&header).unwrap();
+    record.serialize(&mut data).unwrap();
+    data
+}
+
+/// An info account created with `reserve_bytes` of zeroed headroom.
+pub fn info_account_data(info: &TokenInfo, reserve_bytes: usize, written_at_slot: u64) -> Vec<u8> {
+    let mut data = account_data(info, written_at_slot);
+    data.resize(data.len() + reserve_bytes, 0);
+    data
+}
+
+/// An info account of the original deployment: a V1 record behind the legacy
+/// `[magic, version]` header.
+pub fn legacy_info_account_data(info: TokenInfoV1) -> Vec<u8> {
+    let mut data = vec![MAGIC_BYTE, LEGACY_DATA_VERSION];
+    TokenInfo::V1(info).serialize(&mut data).unwrap();
+    data
+}
+
+/// An endorsement marker, which is only a header.
+pub fn endorsement_data(written_at_slot: u64) -> Vec<u8> {
+    borsh::to_vec(&AccountHeader {
+        magic: ENDORSEMENT_MAGIC_BYTE,
+        schema_version: ENDORSEMENT_VERSION,
+        written_at_slot,
+    })
+    .unwrap()
+}
+
+/// A V2 record filled with fixed sample content, to tweak field by field.
+pub fn sample_info(mint: &Pubkey, creator: &Pubkey) -> TokenInfoV2 {
+    TokenInfoV2 {
+        mint: *mint,
+        creator: *creator,
+        payer: *creator,
+        creation_timestamp: 1_700_000_000,
+        creation_slot: 250_000_000,
+        update_timestamp: 1_700_000_000,
+        mint_decimals: 9,
+        mint_supply_at_creation: 1_000_000_000_000_000,
+        verified: false,
+        verified_at: 0,
+        challenged: false,
+        reserved_bytes: 0,
+        endorsements: 0,
+        symbol: search_key::<SYMBOL_LEN>("SMPL").unwrap(),
+        name: search_key::<NAME_LEN>("Sample Token").unwrap(),
+        origin: None,
+        description_format: DescriptionFormat::PlainText,
+        timeline: LaunchTimeline::default(),
+        description: "A sample token".into(),
+        links: vec![Link {
+            label: "Website".into(),
+            url: "https://example.com".into(),
+        }],
+        images: Images {
+            icon: "https://example.com/icon.png".into(),
+            header: "https://example.com/header.png".into(),
+        },
+        image_metadata: ImagesMetadata::default(),
+    }
+}
@@ -0,0 +1,152 @@
+//! Syntactic checks for `ipfs://` URIs. These only look at the CID encoding;
+//! they can't tell whether the content is actually pinned anywhere.
+
+pub const IPFS_SCHEME: &str = "ipfs://";
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const CID_V0_LEN: usize = 46;
+const CID_V1_MIN_LEN: usize = 8;
+const CID_V1_MAX_LEN: usize = 128;
+const MAX_DIGEST_LEN: u64 = 64;
+
+/// Returns the CID part of an `ipfs://<cid>[/path]` URI, or `None` for any
+/// other scheme.
+pub fn ipfs_cid(uri: &str) -> Option<&str> {
+    let rest = uri.strip_prefix(IPFS_SCHEME)?;
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// Accepts base58btc CIDv0 (`Qm...`, sha2-256) and base32 CIDv1 (`b...`).
+pub fn is_valid_cid(cid: &str) -> bool {
+    if cid.starts_with("Qm") {
+        is_valid_cid_v0(cid)
+    } else if let Some(encoded) = cid.strip_prefix('b') {
+        is_valid_cid_v1(encoded)
+    } else {
+        false
+    }
+}
+
+/// Non-IPFS URIs pass through untouched; IPFS ones need a well-formed CID.
+pub fn is_valid_ipfs_uri(uri: &str) -> bool {
+    match ipfs_cid(uri) {
+        Some(cid) => is_valid_cid(cid),
+        None => true,
+    }
+}
+
+fn is_valid_cid_v0(cid: &str) -> bool {
+    cid.len() == CID_V0_LEN && cid.bytes().all(|c| BASE58_ALPHABET.contains(&c))
+}
+
+fn is_valid_cid_v1(encoded: &str) -> bool {
+    if encoded.len() < CID_V1_MIN_LEN || encoded.len() > CID_V1_MAX_LEN {
+        return false;
+    }
+
+    let mut bytes = [0u8; CID_V1_MAX_LEN * 5 / 8];
+    let Some(len) = decode_base32(encoded.as_bytes(), &mut bytes) else {
+        return false;
+    };
+    let mut cursor = &bytes[..len];
+
+    // <version><codec><multihash code><digest length><digest>
+    if read_varint(&mut cursor) != Some(1) {
+        return false;
+    }
+    if read_varint(&mut cursor).is_none() || read_varint(&mut cursor).is_none() {
+        return false;
+    }
+    match read_varint(&mut cursor) {
+        Some(digest_len) if digest_len > 0 && digest_len <= MAX_DIGEST_LEN => {
+            cursor.len() as u64 == digest_len
+        }
+        _ => false,
+    }
+}
+
+/// RFC 4648 lowercase base32 without padding. Returns the decoded length, or
+/// `None` on an invalid character or non-zero trailing bits.
+fn decode_base32(input: &[u8], out: &mut [u8]) -> Option<usize> {
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    let mut len = 0;
+
+    for &c in input {
+        let value = match c {
+            b'a'..=b'z' => c - b'a',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            *out.get_mut(len)? = (buffer >> bits) as u8;
+            buffer &= (1 << bits) - 1;
+            len += 1;
+        }
+    }
+
+    if bits >= 5 || buffer != 0 {
+        return None;
+    }
+    Some(len)
+}
+
+/// Unsigned LEB128 as used by multiformats, capped at 9 bytes.
+fn read_varint(cursor: &mut &[u8]) -> Option<u64> {
+    let mut value: u64 = 0;
+    for (i, &byte) in cursor.iter().enumerate().take(9) {
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            *cursor = &cursor[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_cid_v0_and_v1() {
+        assert!(is_valid_cid(
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+        ));
+        assert!(is_valid_cid(
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_cids() {
+        // `0` isn't base58.
+        assert!(!is_valid_cid(
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0"
+        ));
+        assert!(!is_valid_cid(
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd"
+        ));
+        // Digest one byte short.
+        assert!(!is_valid_cid(
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz"
+        ));
+        assert!(!is_valid_cid(
+            "zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7"
+        ));
+    }
+
+    #[test]
+    fn checks_only_ipfs_uris() {
+        assert_eq!(
+            ipfs_cid("ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG/icon.png"),
+            Some("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG")
+        );
+        assert!(is_valid_ipfs_uri("https://example.com/icon.png"));
+        assert!(!is_valid_ipfs_uri("ipfs://not-a-cid"));
+    }
+}
@@ -0,0 +1,187 @@
+//! Canonical JSON for info records, so independent indexers produce
+//! byte-identical documents (and therefore identical hashes) for the same
+//! account.
+//!
+//! Rules:
+//! - compact output, object keys in lexicographic order;
+//! - pubkeys as base58 strings, `u64` values as decimal strings (JSON numbers
+//!   lose precision above 2^53), `i64` timestamps and `u8` as numbers;
+//! - absent timeline entries, and fields a record version doesn't have, as
+//!   `null`;
+//! - image hashes as lowercase hex;
+//! - URIs trimmed, with the scheme lowercased, and the host too for
+//!   `http`/`https`. Paths and IPFS CIDs are case-sensitive and kept as is.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::Serialize;
+
+use crate::{DescriptionFormat, ImageMetadata, Images, LaunchTimeline, Link, TokenInfo};
+
+impl TokenInfo {
+    pub fn to_canonical_json(&self) -> String {
+        let document = match self {
+            TokenInfo::V1(info) => Document {
+                creation_slot: None,
+                creation_timestamp: info.creation_timestamp,
+                creator: None,
+                description: &info.description,
+                description_format: None,
+                image_metadata: None,
+                images: CanonicalImages::new(&info.images),
+                links: canonical_links(&info.links),
+                mint: info.mint.trim().to_string(),
+                mint_decimals: None,
+                mint_supply_at_creation: None,
+                payer: None,
+                timeline: None,
+                update_timestamp: info.update_timestamp,
+                verified: None,
+                version: 1,
+            },
+            TokenInfo::V2(info) => Document {
+                creation_slot: Some(info.creation_slot.to_string()),
+                creation_timestamp: info.creation_timestamp,
+                creator: Some(info.creator.to_string()),
+                description: &info.description,
+                description_format: Some(match info.description_format {
+                    DescriptionFormat::PlainText => "plain_text",
+                    DescriptionFormat::Markdown => "markdown",
+                }),
+                image_metadata: Some(CanonicalImagesMetadata {
+                    header: CanonicalImageMetadata::new(&info.image_metadata.header),
+                    icon: CanonicalImageMetadata::new(&info.image_metadata.icon),
+                }),
+                images: CanonicalImages::new(&info.images),
+                links: canonical_links(&info.links),
+                mint: info.mint.to_string(),
+                mint_decimals: Some(info.mint_decimals),
+                mint_supply_at_creation: Some(info.mint_supply_at_creation.to_string()),
+                payer: Some(info.payer.to_string()),
+                timeline: Some(CanonicalTimeline::new(&info.timeline)),
+                update_timestamp: info.update_timestamp,
+                verified: Some(info.verified),
+                version: 2,
+            },
+        };
+        // Only strings, numbers, bools and nulls: serialization can't fail.
+        serde_json::to_string(&document).unwrap()
+    }
+}
+
+/// Lowercases the scheme (and the host of web URIs) and trims whitespace.
+pub fn normalize_uri(uri: &str) -> String {
+    let uri = uri.trim();
+    let Some((scheme, rest)) = uri.split_once("://") else {
+        return uri.to_string();
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    let rest = if scheme == "http" || scheme == "https" {
+        let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let mut rest = rest.to_string();
+        rest[..end].make_ascii_lowercase();
+        rest
+    } else {
+        rest.to_string()
+    };
+    scheme + "://" + &rest
+}
+
+// Field order is the serialized key order: keep it sorted.
+#[derive(Serialize)]
+struct Document<'a> {
+    creation_slot: Option<String>,
+    creation_timestamp: i64,
+    creator: Option<String>,
+    description: &'a str,
+    description_format: Option<&'static str>,
+    image_metadata: Option<CanonicalImagesMetadata<'a>>,
+    images: CanonicalImages,
+    links: Vec<CanonicalLink<'a>>,
+    mint: String,
+    mint_decimals: Option<u8>,
+    mint_supply_at_creation: Option<String>,
+    payer: Option<String>,
+    timeline: Option<CanonicalTimeline>,
+    update_timestamp: i64,
+    verified: Option<bool>,
+    version: u8,
+}
+
+#[derive(Serialize)]
+struct CanonicalImages {
+    header: String,
+    icon: String,
+}
+
+impl CanonicalImages {
+    fn new(images: &Images) -> Self {
+        Self {
+            header: normalize_uri(&images.header),
+            icon: normalize_uri(&images.icon),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CanonicalImagesMetadata<'a> {
+    header: CanonicalImageMetadata<'a>,
+    icon: CanonicalImageMetadata<'a>,
+}
+
+#[derive(Serialize)]
+struct CanonicalImageMetadata<'a> {
+    height: Option<u32>,
+    mime: Option<&'a str>,
+    sha256: Option<String>,
+    width: Option<u32>,
+}
+
+impl<'a> CanonicalImageMetadata<'a> {
+    fn new(metadata: &'a ImageMetadata) -> Self {
+        Self {
+            height: metadata.height,
+            mime: metadata.mime.as_deref(),
+            sha256: metadata
+                .sha256
+                .map(|sha256| sha256.iter().map(|byte| format!("{byte:02x}")).collect()),
+            width: metadata.width,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CanonicalLink<'a> {
+    label: &'a str,
+    url: String,
+}
+
+fn canonical_links(links: &[Link]) -> Vec<CanonicalLink<'_>> {
+    links
+        .iter()
+        .map(|link| CanonicalLink {
+            label: &link.label,
+            url: normalize_uri(&link.url),
+        })
+        .collect()
+}
+
+#[derive(Serialize)]
+struct CanonicalTimeline {
+    launch_ts: Option<i64>,
+    listing_ts: Option<i64>,
+    presale_end: Option<i64>,
+    presale_start: Option<i64>,
+}
+
+impl CanonicalTimeline {
+    fn new(timeline: &LaunchTimeline) -> Self {
+        Self {
+            launch_ts: timeline.launch_ts,
+            listing_ts: timeline.listing_ts,
+            presale_end: timeline.presale_end,
+            presale_start: timeline.presale_start,
+        }
+    }
+}
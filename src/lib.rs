@@ -1,65 +1,365 @@
 #![no_std]
 extern crate alloc;
+use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
-use alloc::string::ToString;
+use alloc::vec;
 use alloc::vec::Vec;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::{
-    account_info::{AccountInfo, next_account_info},
+    account_info::AccountInfo,
     clock, entrypoint,
     entrypoint::ProgramResult,
+    hash::{Hash, hash, hashv},
+    instruction::{TRANSACTION_LEVEL_STACK_HEIGHT, get_stack_height},
     msg,
-    program::{invoke, invoke_signed},
+    program::{invoke, invoke_signed, set_return_data},
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction,
-    sysvar::Sysvar,
+    system_instruction, system_program,
+    sysvar::{Sysvar, instructions},
 };
 
+pub mod accounts;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod compute_budget;
+pub mod events;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+pub mod ipfs;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod oracle;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+pub mod token;
+pub mod validation;
+
 entrypoint!(process_instruction);
 
-pub const FEE_RECEIVER: Pubkey = Pubkey::new_from_array([
+const MAINNET_FEE_RECEIVER: Pubkey = Pubkey::new_from_array([
     183, 231, 26, 4, 170, 254, 122, 189, 151, 227, 199, 150, 219, 140, 137, 241, 208, 247, 231,
     185, 96, 41, 98, 183, 121, 165, 132, 99, 187, 65, 128, 48,
 ]);
 
-pub const AUTHORITY: Pubkey = Pubkey::new_from_array([
+const MAINNET_AUTHORITY: Pubkey = Pubkey::new_from_array([
     115, 70, 176, 17, 40, 35, 186, 108, 103, 93, 119, 77, 253, 9, 55, 46, 172, 41, 201, 158, 104,
     244, 46, 182, 56, 25, 197, 36, 89, 84, 13, 104,
 ]);
 
+#[cfg(not(feature = "devnet"))]
+pub const FEE_RECEIVER: Pubkey = MAINNET_FEE_RECEIVER;
+#[cfg(not(feature = "devnet"))]
+pub const AUTHORITY: Pubkey = MAINNET_AUTHORITY;
+
+// With `devnet`, keys the tester holds can be baked in at build time:
+// `LAUNCH_LOCK_AUTHORITY=<pubkey> cargo build-sbf --features devnet`. Unset
+// variables keep the mainnet keys.
+#[cfg(feature = "devnet")]
+pub const FEE_RECEIVER: Pubkey = cluster_key(
+    option_env!("LAUNCH_LOCK_FEE_RECEIVER"),
+    MAINNET_FEE_RECEIVER,
+);
+#[cfg(feature = "devnet")]
+pub const AUTHORITY: Pubkey = cluster_key(option_env!("LAUNCH_LOCK_AUTHORITY"), MAINNET_AUTHORITY);
+
+#[cfg(feature = "devnet")]
+const fn cluster_key(key: Option<&str>, mainnet: Pubkey) -> Pubkey {
+    match key {
+        Some(key) => Pubkey::from_str_const(key),
+        None => mainnet,
+    }
+}
+
+/// Tenant of the original deployment. Its seed is empty, so its PDAs keep the
+/// addresses they had before tenants were introduced.
+pub const GLOBAL_TENANT: Pubkey = Pubkey::new_from_array([0; 32]);
+
 pub const MAGIC_BYTE: u8 = 0xAB;
-pub const DATA_VERSION: u8 = 1;
+pub const DATA_VERSION: u8 = 3;
+pub const CONFIG_MAGIC_BYTE: u8 = 0xAC;
+pub const CONFIG_VERSION: u8 = 2;
+pub const BLACKLIST_MAGIC_BYTE: u8 = 0xAD;
+pub const BLACKLIST_VERSION: u8 = 2;
+pub const PENDING_ACTION_MAGIC_BYTE: u8 = 0xAE;
+pub const PENDING_ACTION_VERSION: u8 = 2;
+pub const FEE_STATS_MAGIC_BYTE: u8 = 0xAF;
+pub const FEE_STATS_VERSION: u8 = 2;
+pub const CREATION_QUOTA_MAGIC_BYTE: u8 = 0xB0;
+pub const CREATION_QUOTA_VERSION: u8 = 2;
+pub const LINK_ATTESTATIONS_MAGIC_BYTE: u8 = 0xB1;
+pub const LINK_ATTESTATIONS_VERSION: u8 = 2;
+pub const RECEIPT_MAGIC_BYTE: u8 = 0xB2;
+pub const RECEIPT_VERSION: u8 = 2;
+pub const ENDORSEMENT_MAGIC_BYTE: u8 = 0xB3;
+pub const ENDORSEMENT_VERSION: u8 = 2;
+/// An endorsement marker holds only its header; its address is the record.
+pub const ENDORSEMENT_LEN: usize = AccountHeader::LEN;
+pub const CHALLENGE_MAGIC_BYTE: u8 = 0xB4;
+pub const CHALLENGE_VERSION: u8 = 2;
+pub const LISTING_BOND_MAGIC_BYTE: u8 = 0xB5;
+pub const LISTING_BOND_VERSION: u8 = 2;
+pub const OPERATOR_MAGIC_BYTE: u8 = 0xB6;
+pub const OPERATOR_VERSION: u8 = 2;
+/// First `DATA_VERSION` written with an `AccountHeader`. Info records below
+/// it, the V1 records of the original deployment among them, start with only
+/// `[magic, version]`.
+pub const STAMPED_DATA_VERSION: u8 = 3;
+/// `DATA_VERSION` of the original deployment's V1 records.
+pub const LEGACY_DATA_VERSION: u8 = 1;
+pub const LEGACY_HEADER_LEN: usize = 2;
+
+/// Prefix of every account the program writes: the account type's magic
+/// byte, the layout version of the body that follows (the `*_VERSION`
+/// constants), and the slot of the last write, stamped by every writer.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountHeader {
+    pub magic: u8,
+    pub schema_version: u8,
+    pub written_at_slot: u64,
+}
+
+impl AccountHeader {
+    pub const LEN: usize = 1 + 1 + 8;
+
+    /// The header of raw account data, for tooling that sorts accounts by type
+    /// and freshness before decoding their bodies. Legacy info records predate
+    /// the slot stamp and read as slot 0.
+    pub fn read(data: &[u8]) -> Option<Self> {
+        if is_legacy_info(data) {
+            return Some(Self {
+                magic: MAGIC_BYTE,
+                schema_version: data[1],
+                written_at_slot: 0,
+            });
+        }
+        Self::try_from_slice(data.get(..Self::LEN)?).ok()
+    }
+}
+
+fn is_legacy_info(data: &[u8]) -> bool {
+    data.len() >= LEGACY_HEADER_LEN && data[0] == MAGIC_BYTE && data[1] < STAMPED_DATA_VERSION
+}
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+pub const FEE_AMOUNT: u64 = 100_000_000;
+
+/// Bumped whenever instructions or account layouts change, so clients can
+/// check what a cluster's build supports through `GetApiVersion` and pin a
+/// minimum with `Instruction::Versioned`.
+pub const PROGRAM_API_VERSION: u32 = 1;
+// Lamports the payer must keep on top of fee + rent so the transaction itself
+// doesn't fail after the fee transfer has already gone through.
+pub const TX_FEE_HEADROOM: u64 = 10_000;
+
+pub const DEFAULT_MAX_ACCOUNT_SIZE: u32 = 4096;
+pub const DEFAULT_FEE_RECEIVER_DELAY: i64 = 2 * SECONDS_PER_DAY;
+pub const DEFAULT_LISTING_BOND_LOCK: i64 = 30 * SECONDS_PER_DAY;
+pub const MAX_ALLOW_LIST_LEN: usize = 64;
+pub const MAX_LOYALTY_TIERS: usize = 8;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Longest delay or lock a config may hold.
+pub const MAX_CONFIG_DELAY: i64 = 365 * SECONDS_PER_DAY;
+pub const MAX_MIME_LEN: usize = 64;
+/// Byte lengths of the normalized `TokenInfoV2::symbol` and `name`.
+pub const SYMBOL_LEN: usize = 10;
+pub const NAME_LEN: usize = 32;
+/// Cap for instructions carrying strings or lists: the largest payload a CPI
+/// can pass.
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 10 * 1024;
 
 #[derive(Debug)]
 pub enum TokenInfoError {
     InvalidInstruction,
     AccountAlreadyExists,
-    InsufficientFunds,
+    InsufficientFunds {
+        required: u64,
+        available: u64,
+    },
     InvalidLinkData,
+    SponsorshipCapExceeded {
+        cap: u64,
+        spent: u64,
+        requested: u64,
+    },
+    AccountTooLarge {
+        size: u64,
+        max: u32,
+    },
+    DescriptionFormatNotAllowed,
+    InvalidIpfsCid,
+    InvalidMint,
+    InvalidTimeline,
+    LegacyRecord,
+    TokenProgramNotAllowed,
+    MintNotAllowed,
+    /// No longer returned: a full list is a `ConfigViolation::TooManyEntries`.
+    AllowListFull,
+    MintBlacklisted,
+    NoPendingFeeReceiver,
+    TimelockNotElapsed {
+        executable_at: i64,
+        now: i64,
+    },
+    TimelockRequired,
+    /// No longer returned: bad tiers are reported as `ConfigViolation`s.
+    InvalidLoyaltyTiers,
+    InvalidPriceFeed,
+    StalePrice {
+        publish_time: i64,
+        now: i64,
+    },
+    PriceConfidenceTooWide {
+        conf_bps: u64,
+        max_bps: u16,
+    },
+    QuotaExceeded {
+        quota: u32,
+    },
+    InvalidImageMetadata,
+    InstructionTooLarge {
+        len: u64,
+        max: u64,
+    },
+    FeeNotPrepaid {
+        fee: u64,
+    },
+    AlreadyRefunded,
+    RefundExceedsFee {
+        amount: u64,
+        fee: u64,
+    },
+    DeprecatedInstruction {
+        cutover_slot: u64,
+    },
+    NotChallengeable,
+    BondLocked {
+        unlocks_at: i64,
+    },
+    DuplicateAccount,
+    InvalidSearchKey,
+    InvalidOperatorShare {
+        share_bps: u16,
+    },
+    InvalidConfig {
+        violations: u32,
+    },
+    UnsupportedApiVersion {
+        required: u32,
+        deployed: u32,
+    },
+    ChallengePending,
+    BondReleased,
+}
+
+impl TokenInfoError {
+    pub fn code(&self) -> u32 {
+        match self {
+            TokenInfoError::InvalidInstruction => 0,
+            TokenInfoError::AccountAlreadyExists => 1,
+            TokenInfoError::InsufficientFunds { .. } => 2,
+            TokenInfoError::InvalidLinkData => 3,
+            TokenInfoError::SponsorshipCapExceeded { .. } => 4,
+            TokenInfoError::AccountTooLarge { .. } => 5,
+            TokenInfoError::DescriptionFormatNotAllowed => 6,
+            TokenInfoError::InvalidIpfsCid => 7,
+            TokenInfoError::InvalidMint => 8,
+            TokenInfoError::InvalidTimeline => 9,
+            TokenInfoError::LegacyRecord => 10,
+            TokenInfoError::TokenProgramNotAllowed => 11,
+            TokenInfoError::MintNotAllowed => 12,
+            TokenInfoError::AllowListFull => 13,
+            TokenInfoError::MintBlacklisted => 14,
+            TokenInfoError::NoPendingFeeReceiver => 15,
+            TokenInfoError::TimelockNotElapsed { .. } => 16,
+            TokenInfoError::TimelockRequired => 17,
+            TokenInfoError::InvalidLoyaltyTiers => 18,
+            TokenInfoError::InvalidPriceFeed => 19,
+            TokenInfoError::StalePrice { .. } => 20,
+            TokenInfoError::PriceConfidenceTooWide { .. } => 21,
+            TokenInfoError::QuotaExceeded { .. } => 22,
+            TokenInfoError::InvalidImageMetadata => 23,
+            TokenInfoError::InstructionTooLarge { .. } => 24,
+            TokenInfoError::FeeNotPrepaid { .. } => 25,
+            TokenInfoError::AlreadyRefunded => 26,
+            TokenInfoError::RefundExceedsFee { .. } => 27,
+            TokenInfoError::DeprecatedInstruction { .. } => 28,
+            TokenInfoError::NotChallengeable => 29,
+            TokenInfoError::BondLocked { .. } => 30,
+            TokenInfoError::DuplicateAccount => 31,
+            TokenInfoError::InvalidSearchKey => 32,
+            TokenInfoError::InvalidOperatorShare { .. } => 33,
+            TokenInfoError::InvalidConfig { .. } => 34,
+            TokenInfoError::UnsupportedApiVersion { .. } => 35,
+            TokenInfoError::ChallengePending => 36,
+            TokenInfoError::BondReleased => 37,
+        }
+    }
 }
 
 impl From<TokenInfoError> for ProgramError {
     fn from(e: TokenInfoError) -> Self {
-        ProgramError::Custom(e as u32)
+        ProgramError::Custom(e.code())
     }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Images {
     pub icon: String,
     pub header: String,
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+/// What clients need to lay out an image before fetching it and to verify
+/// the bytes they get.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageMetadata {
+    /// `image/*` MIME type.
+    pub mime: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub sha256: Option<[u8; 32]>,
+}
+
+/// Metadata of `Images`, V2 records only.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImagesMetadata {
+    pub icon: ImageMetadata,
+    pub header: ImageMetadata,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Link {
     pub label: String,
     pub url: String,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescriptionFormat {
+    PlainText,
+    Markdown,
+}
+
+impl DescriptionFormat {
+    pub const ALL: u8 = 0b11;
+
+    /// Bit used for this format in `Config::allowed_description_formats`.
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LaunchTimeline {
+    pub launch_ts: Option<i64>,
+    pub presale_start: Option<i64>,
+    pub presale_end: Option<i64>,
+    pub listing_ts: Option<i64>,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct TokenInfoV1 {
     pub mint: String,
@@ -70,196 +370,4245 @@ pub struct TokenInfoV1 {
     pub update_timestamp: i64,
 }
 
+// Fixed-size fields come first so they sit at stable offsets for memcmp filters.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct TokenInfoV2 {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    /// Wallet that funded the account: the payer, or the sponsorship vault.
+    pub payer: Pubkey,
+    pub creation_timestamp: i64,
+    pub creation_slot: u64,
+    pub update_timestamp: i64,
+    pub mint_decimals: u8,
+    pub mint_supply_at_creation: u64,
+    /// Set by the admin through `SetVerifiedBatch`. Only counts from
+    /// `verified_at` on, and not while challenged; see `is_verified`.
+    pub verified: bool,
+    /// End of the dispute window of the current verification.
+    pub verified_at: i64,
+    /// A `ChallengeVerification` is awaiting the admin's `ResolveChallenge`.
+    pub challenged: bool,
+    /// Zeroed bytes past the record: the headroom requested at create time,
+    /// kept current by every rewrite. Updates use it before reallocating;
+    /// `TrimInfoAccount` releases it.
+    pub reserved_bytes: u32,
+    /// Distinct wallets that called `Endorse`.
+    pub endorsements: u64,
+    /// `search_key` of the symbol and name given at create time, for memcmp
+    /// searches; all zeros when not given.
+    pub symbol: [u8; SYMBOL_LEN],
+    pub name: [u8; NAME_LEN],
+    /// Launchpad that submitted the record; it signed the create.
+    pub origin: Option<Pubkey>,
+    pub description_format: DescriptionFormat,
+    pub timeline: LaunchTimeline,
+    pub description: String,
+    pub links: Vec<Link>,
+    pub images: Images,
+    pub image_metadata: ImagesMetadata,
+}
+
+/// Normalizes a symbol or name for `TokenInfoV2`: trimmed, uppercased and
+/// zero-padded to `N` bytes, so a memcmp filter built from the same input
+/// matches regardless of case. `None` if the key is empty, longer than `N`
+/// bytes once uppercased, or contains NUL, which would read as padding.
+pub fn search_key<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let value = value.trim().to_uppercase();
+    if value.is_empty() || value.len() > N || value.contains('\0') {
+        return None;
+    }
+    let mut key = [0; N];
+    key[..value.len()].copy_from_slice(value.as_bytes());
+    Some(key)
+}
+
+impl TokenInfo {
+    /// Decodes the data of an info account, legacy two-byte header or
+    /// `AccountHeader`. Reserved headroom may follow the record.
+    pub fn from_account_data(data: &[u8]) -> Option<Self> {
+        let offset = if is_legacy_info(data) {
+            LEGACY_HEADER_LEN
+        } else {
+            AccountHeader::LEN
+        };
+        Self::deserialize(&mut data.get(offset..)?).ok()
+    }
+}
+
+impl TokenInfoV2 {
+    /// Whether the verification is in effect at unix time `now`.
+    pub fn is_verified(&self, now: i64) -> bool {
+        self.verified && !self.challenged && now >= self.verified_at
+    }
+}
+
+// Decoded one record at a time; boxing V2 would only complicate matching.
+#[allow(clippy::large_enum_variant)]
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub enum TokenInfo {
     V1(TokenInfoV1),
+    V2(TokenInfoV2),
+}
+
+// Sponsorship spend is tracked per UTC day (unix_timestamp / SECONDS_PER_DAY).
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub sponsorship_daily_cap: u64,
+    pub sponsorship_day: i64,
+    pub sponsorship_spent_today: u64,
+    pub max_account_size: u32,
+    pub allowed_description_formats: u8,
+    /// Empty means any token program (spl-token or token-2022).
+    pub allowed_token_programs: Vec<Pubkey>,
+    /// Empty means any mint.
+    pub allowed_mints: Vec<Pubkey>,
+    pub fee_receiver: Pubkey,
+    pub fee_receiver_delay: i64,
+    pub pending_fee_receiver: Option<PendingFeeReceiver>,
+    /// Delay applied to queued admin actions. While non-zero, the direct
+    /// setters are refused and changes must go through `QueueAdminAction`.
+    pub admin_delay: i64,
+    pub next_action_id: u64,
+    /// Sorted by `min_paid_creations`; the highest tier reached applies.
+    pub loyalty_tiers: Vec<LoyaltyTier>,
+    /// When set, the base fee tracks a USD amount instead of `FEE_AMOUNT`.
+    pub usd_fee: Option<UsdFee>,
+    /// Seeds every other PDA governed by this config. `GLOBAL_TENANT` for the
+    /// config created by `InitConfig`.
+    pub tenant: Pubkey,
+    /// Creates per creator wallet per day, the admin excepted. Zero disables
+    /// the quota.
+    pub creator_daily_quota: u32,
+    /// May call `AttestLink` besides the admin.
+    pub link_attester: Option<Pubkey>,
+    /// From this slot on the legacy `CreateInfo` payload is rejected in favor
+    /// of `CreateInfoV2`.
+    pub v1_cutover_slot: Option<u64>,
+    /// Seconds after `SetVerifiedBatch` during which a verification can be
+    /// challenged before it takes effect. Zero applies it right away.
+    pub verification_dispute_delay: i64,
+    /// Lamports a challenger escrows; forfeited to the fee receiver if the
+    /// admin rejects the challenge.
+    pub challenge_bond: u64,
+    /// Seconds a listing bond stays locked before its creator can reclaim it.
+    pub listing_bond_lock: i64,
+    /// Records created under this config, through any create instruction.
+    /// Every create writes it, so creates within a tenant take turns on the
+    /// config's write lock; that's the price of an exact total.
+    pub total_creates: u64,
+    /// The sponsored part of `total_creates`.
+    pub sponsored_creates: u64,
+    /// Co-signs every create under this config. `None`, as in the config of
+    /// `GLOBAL_TENANT`, falls back to `AUTHORITY`; tenants start with their
+    /// own key.
+    pub authority: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsdFee {
+    /// Pyth feed id of the SOL/USD price, see `oracle::SOL_USD_FEED_ID`.
+    pub feed_id: [u8; 32],
+    /// Target fee in 1e-6 USD.
+    pub micro_usd: u64,
+    pub max_staleness_secs: i64,
+    pub max_confidence_bps: u16,
+    /// Bounds on the converted fee, in case the feed misbehaves.
+    pub min_lamports: u64,
+    pub max_lamports: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoyaltyTier {
+    /// Paid creates already recorded in the payer's fee stats.
+    pub min_paid_creations: u64,
+    pub discount_bps: u16,
+}
+
+impl Config {
+    pub fn default_loyalty_tiers() -> Vec<LoyaltyTier> {
+        vec![
+            LoyaltyTier {
+                min_paid_creations: 10,
+                discount_bps: 1_000,
+            },
+            LoyaltyTier {
+                min_paid_creations: 50,
+                discount_bps: 2_500,
+            },
+        ]
+    }
+
+    /// The key that must co-sign creates under this config.
+    pub fn create_authority(&self) -> Pubkey {
+        self.authority.unwrap_or(AUTHORITY)
+    }
+
+    /// `base_fee` after the discount for a payer with `paid_creations` prior
+    /// paid creates.
+    pub fn discounted_fee(&self, base_fee: u64, paid_creations: u64) -> u64 {
+        let discount_bps = self
+            .loyalty_tiers
+            .iter()
+            .rev()
+            .find(|tier| paid_creations >= tier.min_paid_creations)
+            .map_or(0, |tier| tier.discount_bps as u64);
+        base_fee - (base_fee as u128 * discount_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    /// Settings this config must not have. This is the one place config rules
+    /// live: every config write, admin setters included, is refused while it
+    /// is non-empty, and `ValidateConfig` returns it.
+    pub fn violations(&self) -> Vec<ConfigViolation> {
+        let mut violations = Vec::new();
+        let keys = [
+            (ConfigField::Admin, Some(self.admin)),
+            (ConfigField::FeeReceiver, Some(self.fee_receiver)),
+            (ConfigField::Authority, self.authority),
+            (
+                ConfigField::PendingFeeReceiver,
+                self.pending_fee_receiver.map(|pending| pending.candidate),
+            ),
+        ];
+        for (field, key) in keys {
+            if key == Some(Pubkey::default()) {
+                violations.push(ConfigViolation::ZeroKey { field });
+            }
+        }
+        let delays = [
+            (ConfigField::FeeReceiverDelay, self.fee_receiver_delay),
+            (ConfigField::AdminDelay, self.admin_delay),
+            (
+                ConfigField::VerificationDisputeDelay,
+                self.verification_dispute_delay,
+            ),
+            (ConfigField::ListingBondLock, self.listing_bond_lock),
+        ]
+        .into_iter()
+        .chain(
+            self.usd_fee
+                .map(|usd_fee| (ConfigField::UsdFee, usd_fee.max_staleness_secs)),
+        );
+        for (field, delay) in delays {
+            if !(0..=MAX_CONFIG_DELAY).contains(&delay) {
+                violations.push(ConfigViolation::DelayOutOfRange { field, delay });
+            }
+        }
+        let bps = self
+            .loyalty_tiers
+            .iter()
+            .map(|tier| (ConfigField::LoyaltyTiers, tier.discount_bps))
+            .chain(
+                self.usd_fee
+                    .map(|usd_fee| (ConfigField::UsdFee, usd_fee.max_confidence_bps)),
+            );
+        for (field, bps) in bps {
+            if bps as u64 > BPS_DENOMINATOR {
+                violations.push(ConfigViolation::BpsOutOfRange { field, bps });
+            }
+        }
+        let lists = [
+            (
+                ConfigField::LoyaltyTiers,
+                self.loyalty_tiers.len(),
+                MAX_LOYALTY_TIERS,
+            ),
+            (
+                ConfigField::AllowedTokenPrograms,
+                self.allowed_token_programs.len(),
+                MAX_ALLOW_LIST_LEN,
+            ),
+            (
+                ConfigField::AllowedMints,
+                self.allowed_mints.len(),
+                MAX_ALLOW_LIST_LEN,
+            ),
+        ];
+        for (field, len, max) in lists {
+            if len > max {
+                violations.push(ConfigViolation::TooManyEntries {
+                    field,
+                    len: len as u32,
+                });
+            }
+        }
+        if self
+            .loyalty_tiers
+            .windows(2)
+            .any(|pair| pair[0].min_paid_creations >= pair[1].min_paid_creations)
+        {
+            violations.push(ConfigViolation::UnorderedLoyaltyTiers);
+        }
+        for key in &self.allowed_token_programs {
+            if !token::is_token_program(key) {
+                violations.push(ConfigViolation::UnsupportedTokenProgram { key: *key });
+            }
+        }
+        if let Some(usd_fee) = self.usd_fee {
+            if usd_fee.min_lamports > usd_fee.max_lamports {
+                violations.push(ConfigViolation::InvertedFeeBounds {
+                    min_lamports: usd_fee.min_lamports,
+                    max_lamports: usd_fee.max_lamports,
+                });
+            }
+        }
+        violations
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigField {
+    Admin,
+    FeeReceiver,
+    PendingFeeReceiver,
+    FeeReceiverDelay,
+    AdminDelay,
+    VerificationDisputeDelay,
+    ListingBondLock,
+    LoyaltyTiers,
+    UsdFee,
+    AllowedTokenPrograms,
+    AllowedMints,
+    Authority,
+}
+
+/// One entry of `Config::violations`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigViolation {
+    /// The all-zero key, which nobody can sign for.
+    ZeroKey {
+        field: ConfigField,
+    },
+    /// Negative, or longer than `MAX_CONFIG_DELAY`.
+    DelayOutOfRange {
+        field: ConfigField,
+        delay: i64,
+    },
+    /// Over `BPS_DENOMINATOR`.
+    BpsOutOfRange {
+        field: ConfigField,
+        bps: u16,
+    },
+    /// Longer than `MAX_LOYALTY_TIERS` or `MAX_ALLOW_LIST_LEN`.
+    TooManyEntries {
+        field: ConfigField,
+        len: u32,
+    },
+    /// Loyalty thresholds not strictly increasing.
+    UnorderedLoyaltyTiers,
+    UnsupportedTokenProgram {
+        key: Pubkey,
+    },
+    /// `UsdFee::min_lamports` above `max_lamports`.
+    InvertedFeeBounds {
+        min_lamports: u64,
+        max_lamports: u64,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct PendingFeeReceiver {
+    pub candidate: Pubkey,
+    pub executable_at: i64,
+}
+
+/// Running totals of fees charged to one payer across paid creates.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct FeeStats {
+    pub payer: Pubkey,
+    pub total_fees_paid: u64,
+    pub paid_creations: u64,
+    pub first_paid_at: i64,
+    pub last_paid_at: i64,
+}
+
+impl FeeStats {
+    pub const LEN: usize = AccountHeader::LEN + 32 + 8 + 8 + 8 + 8;
+}
+
+/// Creates recorded for one creator wallet on `day`, enforcing
+/// `Config::creator_daily_quota`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct CreationQuota {
+    pub wallet: Pubkey,
+    pub day: i64,
+    pub created: u32,
+}
+
+impl CreationQuota {
+    pub const LEN: usize = AccountHeader::LEN + 32 + 8 + 4;
+}
+
+/// Proof of one paid create, written for the payer so fees can be reconciled
+/// and refunded per mint.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct Receipt {
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+    pub info_account: Pubkey,
+    pub fee: u64,
+    pub slot: u64,
+    /// Lamports returned to the payer so far.
+    pub refunded: u64,
+}
+
+impl Receipt {
+    pub const LEN: usize = AccountHeader::LEN + 32 + 32 + 32 + 8 + 8 + 8;
+}
+
+/// Open challenge against a pending verification. Holds the bond on top of
+/// its rent.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct VerificationChallenge {
+    pub challenger: Pubkey,
+    pub mint: Pubkey,
+    pub bond: u64,
+    pub challenged_at: i64,
+}
+
+impl VerificationChallenge {
+    pub const LEN: usize = AccountHeader::LEN + 32 + 32 + 8 + 8;
+}
+
+/// Lamports a creator locked against their record, held on top of the
+/// account's rent.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct ListingBond {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub posted_at: i64,
+}
+
+impl ListingBond {
+    pub const LEN: usize = AccountHeader::LEN + 32 + 32 + 8 + 8;
+}
+
+/// A launchpad registered by the admin. Creates naming it as their origin
+/// send `share_bps` of the fee to `payout` and the rest to the fee receiver;
+/// sponsored creates, paid from the program's own vault, aren't shared.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct Operator {
+    pub operator: Pubkey,
+    pub payout: Pubkey,
+    pub share_bps: u16,
+}
+
+impl Operator {
+    pub const LEN: usize = AccountHeader::LEN + 32 + 32 + 2;
+
+    /// The operator's cut of `fee`, rounded down in the treasury's favour.
+    pub fn share_of(&self, fee: u64) -> u64 {
+        (fee as u128 * self.share_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkStatus {
+    Ok,
+    Broken,
+    Redirected,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkAttestation {
+    /// Hash of the URL that was checked, so a stale attestation can be told
+    /// apart once the link changes.
+    pub url_hash: [u8; 32],
+    pub status: LinkStatus,
+    pub checked_at: i64,
+}
+
+/// Liveness checks of an info record's links, indexed like `links`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LinkAttestations {
+    pub mint: Pubkey,
+    pub links: Vec<Option<LinkAttestation>>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct BlacklistEntry {
+    pub mint: Pubkey,
+    pub added_timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllowList {
+    TokenPrograms,
+    Mints,
+}
+
+/// Config mutations that can be queued behind `Config::admin_delay`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub enum AdminAction {
+    SetSponsorshipCap { daily_cap: u64 },
+    SetMaxAccountSize { max_account_size: u32 },
+    SetAllowedDescriptionFormats { formats: Vec<DescriptionFormat> },
+    AddToAllowList { list: AllowList, key: Pubkey },
+    RemoveFromAllowList { list: AllowList, key: Pubkey },
+    SetFeeReceiverDelay { delay: i64 },
+    SetAdmin { admin: Pubkey },
+    SetAdminDelay { delay: i64 },
+    SetLoyaltyTiers { tiers: Vec<LoyaltyTier> },
+    SetUsdFee { usd_fee: Option<UsdFee> },
+    SetCreatorDailyQuota { quota: u32 },
+    SetLinkAttester { attester: Option<Pubkey> },
+    SetV1CutoverSlot { slot: Option<u64> },
+    SetVerificationDispute { delay: i64, bond: u64 },
+    SetListingBondLock { lock: i64 },
+    SetAuthority { authority: Option<Pubkey> },
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct PendingAction {
+    pub id: u64,
+    pub action: AdminAction,
+    /// Receives the account's rent back once executed or cancelled.
+    pub queued_by: Pubkey,
+    pub queued_at: i64,
+    pub executable_at: i64,
+}
+
+/// Return data of `GetStats`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub tenant: Pubkey,
+    pub total_creates: u64,
+    pub sponsored_creates: u64,
+    pub sponsorship_day: i64,
+    pub sponsorship_spent_today: u64,
+    pub sponsorship_vault_balance: u64,
+    pub next_action_id: u64,
+}
+
+/// Return data of `GetMintStats`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintStats {
+    /// Zero, like everything below, when the record doesn't exist.
+    pub mint: Pubkey,
+    /// Records of the mint under the tenant: one per tenant at most.
+    pub creates: u64,
+    pub creation_slot: u64,
+    pub update_timestamp: i64,
+    pub endorsements: u64,
+    /// `TokenInfoV2::is_verified` as of the call.
+    pub verified: bool,
+    pub challenged: bool,
+    /// Zero when no listing bond is posted.
+    pub listing_bond: u64,
+}
+
+/// Return data of `PreviewCreateInfo`, all amounts in lamports.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateQuote {
+    pub account_size: u64,
+    pub fee: u64,
+    pub rent: u64,
+    pub fee_stats_rent: u64,
+    pub quota_rent: u64,
+    pub receipt_rent: u64,
+    /// Fee, rents and `TX_FEE_HEADROOM`; the payer balance needed to succeed.
+    pub required: u64,
+    pub available: u64,
+}
+
+/// Return data of the create instructions: the info account and its bump, so
+/// callers don't have to derive them again.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreatedInfo {
+    pub info_account: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct CreateInfoArgs {
+    pub description: String,
+    pub description_format: DescriptionFormat,
+    pub links: Vec<Link>,
+    pub icon_uri: String,
+    pub header_uri: String,
+    pub image_metadata: ImagesMetadata,
+    /// Extra bytes allocated (and paid for) past the record.
+    pub reserve_bytes: u32,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+    /// Must sign; pass it after the other accounts, followed by its operator
+    /// PDA and, once registered, the operator's payout account.
+    pub origin: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub enum Instruction {
+    /// The original create: `[payer, authority, mint, info, system_program,
+    /// fee_receiver, (config, (price_update))]`, with the payer recorded as
+    /// creator. Without a config it creates under `GLOBAL_TENANT` on the
+    /// original terms (`AUTHORITY`, `FEE_RECEIVER`, `FEE_AMOUNT`); with one,
+    /// the tenant's authority, fee receiver, fee, allow lists and
+    /// `v1_cutover_slot` apply.
     CreateInfo {
         description: String,
         links: Vec<Link>,
         icon_uri: String,
         header_uri: String,
     },
+    InitConfig {
+        sponsorship_daily_cap: u64,
+    },
+    SetSponsorshipCap {
+        daily_cap: u64,
+    },
+    FundSponsorship {
+        amount: u64,
+    },
+    CreateInfoSponsored(CreateInfoArgs),
+    SetMaxAccountSize {
+        max_account_size: u32,
+    },
+    CreateInfoV2(CreateInfoArgs),
+    SetAllowedDescriptionFormats {
+        formats: Vec<DescriptionFormat>,
+    },
+    UpdateTimeline {
+        timeline: LaunchTimeline,
+    },
+    AddToAllowList {
+        list: AllowList,
+        key: Pubkey,
+    },
+    RemoveFromAllowList {
+        list: AllowList,
+        key: Pubkey,
+    },
+    AddToBlacklist,
+    RemoveFromBlacklist,
+    ProposeFeeReceiver {
+        candidate: Pubkey,
+    },
+    AcceptFeeReceiver,
+    QueueAdminAction {
+        action: AdminAction,
+    },
+    ExecuteAdminAction,
+    CancelAdminAction,
+    SetLoyaltyTiers {
+        tiers: Vec<LoyaltyTier>,
+    },
+    SetUsdFee {
+        usd_fee: Option<UsdFee>,
+    },
+    /// Runs every `CreateInfoV2` check and returns a `CreateQuote` as return
+    /// data without touching any account. Meant for `simulateTransaction`.
+    PreviewCreateInfo(CreateInfoArgs),
+    /// `CreateInfoV2` that succeeds without doing anything when the record
+    /// already exists with the same content.
+    CreateInfoIdempotent(CreateInfoArgs),
+    /// One flag per info account passed after `[admin, config,
+    /// system_program]`. Records with an open challenge are skipped.
+    SetVerifiedBatch {
+        flags: Vec<bool>,
+    },
+    /// Creates the config of a new tenant, co-signed by the authority. The
+    /// tenant key becomes its admin and the authority of its creates.
+    InitTenantConfig {
+        sponsorship_daily_cap: u64,
+        fee_receiver: Pubkey,
+    },
+    SetCreatorDailyQuota {
+        quota: u32,
+    },
+    SetLinkAttester {
+        attester: Option<Pubkey>,
+    },
+    /// Records the checked status of `links[index]` of an info record.
+    AttestLink {
+        index: u32,
+        status: LinkStatus,
+    },
+    /// Replaces both image URIs and their metadata in one write.
+    UpdateImages {
+        icon_uri: String,
+        header_uri: String,
+        image_metadata: ImagesMetadata,
+    },
+    /// `CreateInfoV2` whose fee was paid by a system transfer to the fee
+    /// receiver in the instruction right before it. Takes the instructions
+    /// sysvar after the quota account.
+    CreateInfoPrepaid(CreateInfoArgs),
+    /// Shrinks an info account to its encoded size, dropping any reserved
+    /// headroom, and refunds the rent above the new minimum to the creator.
+    /// Same accounts as `UpdateImages`.
+    TrimInfoAccount,
+    /// Admin returns the full fee of a receipt to its payer, paid by the fee
+    /// receiver, which has to co-sign. A receipt is refunded at most once.
+    RefundReceipt,
+    /// Admin refunds up to a receipt's fee from the tenant's sponsorship
+    /// vault, which also serves as its treasury: `[admin, config, vault,
+    /// payer, receipt, system_program]`. `amount` must be non-zero. Counts
+    /// against the daily sponsorship cap and marks the receipt refunded.
+    RefundFee {
+        amount: u64,
+    },
+    /// Permissionless: logs an `InfoSnapshot` event with the current record so
+    /// indexers can backfill without account history. `[info]`.
+    EmitSnapshot,
+    SetV1CutoverSlot {
+        slot: Option<u64>,
+    },
+    /// Any wallet endorses a V2 record once, paying for a marker PDA:
+    /// `[endorser, info, endorsement, system_program]`.
+    Endorse,
+    SetVerificationDispute {
+        delay: i64,
+        bond: u64,
+    },
+    /// Escrows `Config::challenge_bond` against a verification still in its
+    /// dispute window, holding it back until the admin resolves the challenge:
+    /// `[challenger, info, config, challenge, system_program]`.
+    ChallengeVerification,
+    /// `upheld` revokes the verification and returns the bond; otherwise the
+    /// bond goes to the fee receiver. The challenge rent goes back to the
+    /// challenger either way: `[admin, config, info, challenge, challenger,
+    /// fee_receiver]`.
+    ResolveChallenge {
+        upheld: bool,
+    },
+    SetListingBondLock {
+        lock: i64,
+    },
+    /// The creator locks `amount` lamports against their record:
+    /// `[creator, info, config, bond, system_program]`.
+    PostListingBond {
+        amount: u64,
+    },
+    /// Returns the bond and its rent to the creator once
+    /// `Config::listing_bond_lock` has passed and no challenge is open on the
+    /// record: `[creator, info, config, bond]`.
+    ReclaimBond,
+    /// Admin flags the record as a scam: the bond goes to the fee receiver,
+    /// the rent back to the creator. `[admin, config, info, bond, creator,
+    /// fee_receiver]`.
+    ForfeitBond,
+    /// Admin registers `operator` or updates its entry: `[admin, config,
+    /// operator, operator_account, system_program]`.
+    SetOperator {
+        payout: Pubkey,
+        share_bps: u16,
+    },
+    /// Admin closes the operator's entry; its creates pay the full fee to the
+    /// fee receiver again. Same accounts as `SetOperator`.
+    RemoveOperator,
+    /// Returns the `Config::violations` of the config, after applying `action`
+    /// to a copy when given, as Borsh `Vec<ConfigViolation>` return data.
+    /// Nothing is written: `[config]`.
+    ValidateConfig {
+        action: Option<AdminAction>,
+    },
+    /// Returns the tenant's `Stats` as return data: `[config,
+    /// sponsorship_vault]`.
+    GetStats,
+    /// Returns the record's `MintStats` as return data: `[info,
+    /// listing_bond]`. Either may be empty.
+    GetMintStats,
+    /// Returns `PROGRAM_API_VERSION` as Borsh `u32` return data. No accounts.
+    GetApiVersion,
+    /// Runs `instruction`, with its accounts, only if the deployed
+    /// `PROGRAM_API_VERSION` is at least `min_api_version`. `instruction` keeps
+    /// its own size cap and can't be `Versioned` itself.
+    Versioned {
+        min_api_version: u32,
+        instruction: Box<Instruction>,
+    },
+}
+
+/// Seed namespacing a tenant's PDAs, placed right after each PDA's prefix.
+pub fn tenant_seed(tenant: &Pubkey) -> &[u8] {
+    if *tenant == GLOBAL_TENANT {
+        &[]
+    } else {
+        tenant.as_ref()
+    }
+}
+
+pub fn find_info_account(tenant: &Pubkey, mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"token_info", tenant_seed(tenant), mint.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_config_account(tenant: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config", tenant_seed(tenant)], program_id)
+}
+
+pub fn find_blacklist_account(tenant: &Pubkey, mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"blacklist", tenant_seed(tenant), mint.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_pending_action_account(tenant: &Pubkey, id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"pending_action", tenant_seed(tenant), &id.to_le_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_fee_stats_account(
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"fee_stats", tenant_seed(tenant), payer.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_creation_quota_account(
+    tenant: &Pubkey,
+    wallet: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"creation_quota", tenant_seed(tenant), wallet.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_link_attestations_account(info_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"link_attestations", info_account.as_ref()], program_id)
+}
+
+pub fn find_receipt_account(
+    tenant: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"receipt",
+            tenant_seed(tenant),
+            payer.as_ref(),
+            mint.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn find_endorsement_account(
+    info_account: &Pubkey,
+    endorser: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"endorsement", info_account.as_ref(), endorser.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_challenge_account(info_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"verification_challenge", info_account.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_listing_bond_account(info_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"listing_bond", info_account.as_ref()], program_id)
+}
+
+pub fn find_operator_account(
+    tenant: &Pubkey,
+    operator: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"operator", tenant_seed(tenant), operator.as_ref()],
+        program_id,
+    )
 }
 
-pub fn find_info_account(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[b"token_info", mint.as_ref()], program_id)
+pub fn find_sponsorship_vault(tenant: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"sponsorship_vault", tenant_seed(tenant)], program_id)
 }
 
+/// Largest encoding of the instruction with the given tag (its `Instruction`
+/// variant index). Fixed-size instructions get their exact size, `Option`s
+/// counted as `Some`. A `Versioned` wrapper is checked against the tag of the
+/// instruction it wraps instead.
+pub fn max_instruction_data_len(tag: u8) -> usize {
+    match tag {
+        // InitConfig, SetSponsorshipCap, FundSponsorship
+        1..=3 => 1 + 8,
+        // SetMaxAccountSize
+        5 => 1 + 4,
+        // UpdateTimeline
+        8 => 1 + 4 * 9,
+        // AddToAllowList, RemoveFromAllowList
+        9 | 10 => 1 + 1 + 32,
+        // AddToBlacklist, RemoveFromBlacklist
+        11 | 12 => 1,
+        // ProposeFeeReceiver
+        13 => 1 + 32,
+        // AcceptFeeReceiver
+        14 => 1,
+        // ExecuteAdminAction, CancelAdminAction
+        16 | 17 => 1,
+        // SetLoyaltyTiers
+        18 => 1 + 4 + MAX_LOYALTY_TIERS * (8 + 2),
+        // SetUsdFee
+        19 => 1 + 1 + 32 + 8 + 8 + 2 + 8 + 8,
+        // InitTenantConfig
+        23 => 1 + 8 + 32,
+        // SetCreatorDailyQuota
+        24 => 1 + 4,
+        // SetLinkAttester
+        25 => 1 + 1 + 32,
+        // AttestLink
+        26 => 1 + 4 + 1,
+        // TrimInfoAccount, RefundReceipt
+        29 | 30 => 1,
+        // RefundFee
+        31 => 1 + 8,
+        // EmitSnapshot
+        32 => 1,
+        // SetV1CutoverSlot
+        33 => 1 + 1 + 8,
+        // Endorse
+        34 => 1,
+        // SetVerificationDispute
+        35 => 1 + 8 + 8,
+        // ChallengeVerification
+        36 => 1,
+        // ResolveChallenge
+        37 => 1 + 1,
+        // SetListingBondLock, PostListingBond
+        38 | 39 => 1 + 8,
+        // ReclaimBond, ForfeitBond
+        40 | 41 => 1,
+        // SetOperator
+        42 => 1 + 32 + 2,
+        // RemoveOperator
+        43 => 1,
+        // GetStats, GetMintStats, GetApiVersion
+        45..=47 => 1,
+        _ => MAX_INSTRUCTION_DATA_LEN,
+    }
+}
+
+/// Tag of `Instruction::Versioned`, followed by its `min_api_version`.
+const VERSIONED_TAG: u8 = 48;
+const VERSIONED_HEADER_LEN: usize = 1 + 4;
+
 pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let instruction = Instruction::try_from_slice(instruction_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let Some(&tag) = instruction_data.first() else {
+        msg!("[Error] Empty instruction data");
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    // Size the wrapped instruction by its own tag, so wrapping doesn't lift
+    // its cap. Checked before decoding, which would otherwise recurse through
+    // any depth of nesting.
+    let (tag, data) = match instruction_data.get(VERSIONED_HEADER_LEN..) {
+        Some([VERSIONED_TAG, ..]) if tag == VERSIONED_TAG => {
+            msg!("[Error] Versioned instructions can't be nested");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Some(inner @ [inner_tag, ..]) if tag == VERSIONED_TAG => (*inner_tag, inner),
+        _ => (tag, instruction_data),
+    };
+    let max = max_instruction_data_len(tag);
+    if data.len() > max {
+        msg!(
+            "[Error] Instruction {} data is {} bytes, max {}",
+            tag,
+            data.len(),
+            max
+        );
+        return Err(TokenInfoError::InstructionTooLarge {
+            len: data.len() as u64,
+            max: max as u64,
+        }
+        .into());
+    }
+
+    // `try_from_slice` also fails when bytes are left over, so padded
+    // encodings are refused rather than silently truncated.
+    let instruction = Instruction::try_from_slice(instruction_data).map_err(|e| {
+        msg!(
+            "[Error] Instruction {} doesn't decode: {}",
+            instruction_data[0],
+            e
+        );
+        ProgramError::InvalidInstructionData
+    })?;
 
+    process(program_id, accounts, instruction)
+}
+
+fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: Instruction,
+) -> ProgramResult {
     match instruction {
         Instruction::CreateInfo {
             description,
             links,
             icon_uri,
             header_uri,
-        } => process_create_info(
+        } => process_create_info_legacy(
             program_id,
             accounts,
-            description,
-            links,
+            CreateInfoArgs {
+                description,
+                description_format: DescriptionFormat::PlainText,
+                links,
+                icon_uri,
+                header_uri,
+                image_metadata: ImagesMetadata::default(),
+                reserve_bytes: 0,
+                symbol: None,
+                name: None,
+                origin: None,
+            },
+        ),
+        Instruction::InitConfig {
+            sponsorship_daily_cap,
+        } => process_init_config(program_id, accounts, sponsorship_daily_cap),
+        Instruction::SetSponsorshipCap { daily_cap } => {
+            process_set_sponsorship_cap(program_id, accounts, daily_cap)
+        }
+        Instruction::FundSponsorship { amount } => {
+            process_fund_sponsorship(program_id, accounts, amount)
+        }
+        Instruction::CreateInfoSponsored(args) => {
+            process_create_info_sponsored(program_id, accounts, args)
+        }
+        Instruction::SetMaxAccountSize { max_account_size } => {
+            process_set_max_account_size(program_id, accounts, max_account_size)
+        }
+        Instruction::CreateInfoV2(args) => {
+            process_create_info(program_id, accounts, args, CreateMode::Pay)
+        }
+        Instruction::SetAllowedDescriptionFormats { formats } => {
+            process_set_allowed_description_formats(program_id, accounts, formats)
+        }
+        Instruction::UpdateTimeline { timeline } => {
+            process_update_timeline(program_id, accounts, timeline)
+        }
+        Instruction::AddToAllowList { list, key } => {
+            process_add_to_allow_list(program_id, accounts, list, key)
+        }
+        Instruction::RemoveFromAllowList { list, key } => {
+            process_remove_from_allow_list(program_id, accounts, list, key)
+        }
+        Instruction::AddToBlacklist => process_add_to_blacklist(program_id, accounts),
+        Instruction::RemoveFromBlacklist => process_remove_from_blacklist(program_id, accounts),
+        Instruction::ProposeFeeReceiver { candidate } => {
+            process_propose_fee_receiver(program_id, accounts, candidate)
+        }
+        Instruction::AcceptFeeReceiver => process_accept_fee_receiver(program_id, accounts),
+        Instruction::QueueAdminAction { action } => {
+            process_queue_admin_action(program_id, accounts, action)
+        }
+        Instruction::ExecuteAdminAction => process_execute_admin_action(program_id, accounts),
+        Instruction::CancelAdminAction => process_cancel_admin_action(program_id, accounts),
+        Instruction::SetLoyaltyTiers { tiers } => {
+            process_set_loyalty_tiers(program_id, accounts, tiers)
+        }
+        Instruction::SetUsdFee { usd_fee } => process_set_usd_fee(program_id, accounts, usd_fee),
+        Instruction::PreviewCreateInfo(args) => {
+            process_create_info(program_id, accounts, args, CreateMode::Preview)
+        }
+        Instruction::CreateInfoIdempotent(args) => {
+            process_create_info_idempotent(program_id, accounts, args)
+        }
+        Instruction::SetVerifiedBatch { flags } => {
+            process_set_verified_batch(program_id, accounts, flags)
+        }
+        Instruction::InitTenantConfig {
+            sponsorship_daily_cap,
+            fee_receiver,
+        } => process_init_tenant_config(program_id, accounts, sponsorship_daily_cap, fee_receiver),
+        Instruction::SetCreatorDailyQuota { quota } => {
+            process_set_creator_daily_quota(program_id, accounts, quota)
+        }
+        Instruction::SetLinkAttester { attester } => {
+            process_set_link_attester(program_id, accounts, attester)
+        }
+        Instruction::AttestLink { index, status } => {
+            process_attest_link(program_id, accounts, index, status)
+        }
+        Instruction::UpdateImages {
             icon_uri,
             header_uri,
-        ),
+            image_metadata,
+        } => process_update_images(program_id, accounts, icon_uri, header_uri, image_metadata),
+        Instruction::CreateInfoPrepaid(args) => {
+            process_create_info(program_id, accounts, args, CreateMode::Prepaid)
+        }
+        Instruction::TrimInfoAccount => process_trim_info_account(program_id, accounts),
+        Instruction::RefundReceipt => process_refund_receipt(program_id, accounts),
+        Instruction::RefundFee { amount } => process_refund_fee(program_id, accounts, amount),
+        Instruction::EmitSnapshot => process_emit_snapshot(program_id, accounts),
+        Instruction::SetV1CutoverSlot { slot } => {
+            process_set_v1_cutover_slot(program_id, accounts, slot)
+        }
+        Instruction::Endorse => process_endorse(program_id, accounts),
+        Instruction::SetVerificationDispute { delay, bond } => {
+            process_set_verification_dispute(program_id, accounts, delay, bond)
+        }
+        Instruction::ChallengeVerification => process_challenge_verification(program_id, accounts),
+        Instruction::ResolveChallenge { upheld } => {
+            process_resolve_challenge(program_id, accounts, upheld)
+        }
+        Instruction::SetListingBondLock { lock } => {
+            process_set_listing_bond_lock(program_id, accounts, lock)
+        }
+        Instruction::PostListingBond { amount } => {
+            process_post_listing_bond(program_id, accounts, amount)
+        }
+        Instruction::ReclaimBond => process_reclaim_bond(program_id, accounts),
+        Instruction::ForfeitBond => process_forfeit_bond(program_id, accounts),
+        Instruction::SetOperator { payout, share_bps } => {
+            process_set_operator(program_id, accounts, payout, share_bps)
+        }
+        Instruction::RemoveOperator => process_remove_operator(program_id, accounts),
+        Instruction::ValidateConfig { action } => {
+            process_validate_config(program_id, accounts, action)
+        }
+        Instruction::GetStats => process_get_stats(program_id, accounts),
+        Instruction::GetMintStats => process_get_mint_stats(program_id, accounts),
+        Instruction::GetApiVersion => {
+            msg!("[GetApiVersion] {}", PROGRAM_API_VERSION);
+            set_return_data(&PROGRAM_API_VERSION.to_le_bytes());
+            Ok(())
+        }
+        Instruction::Versioned {
+            min_api_version,
+            instruction,
+        } => {
+            if min_api_version > PROGRAM_API_VERSION {
+                msg!(
+                    "[Error] Instruction needs API version {}, deployed is {}",
+                    min_api_version,
+                    PROGRAM_API_VERSION
+                );
+                return Err(TokenInfoError::UnsupportedApiVersion {
+                    required: min_api_version,
+                    deployed: PROGRAM_API_VERSION,
+                }
+                .into());
+            }
+            process(program_id, accounts, *instruction)
+        }
     }
 }
 
-fn process_create_info(
-    program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    description: String,
-    links: Vec<Link>,
-    icon_uri: String,
-    header_uri: String,
-) -> ProgramResult {
-    msg!("[CreateInfo] Starting token info creation (V1)");
+fn build_info(
+    mint_account: &AccountInfo,
+    creator: &Pubkey,
+    payer: &Pubkey,
+    args: CreateInfoArgs,
+) -> Result<TokenInfoV2, ProgramError> {
+    let clock = clock::Clock::get()?;
+    let ts = clock.unix_timestamp;
+    msg!("[CreateInfo] Timestamp: {}, slot: {}", ts, clock.slot);
 
-    let accounts_iter: &mut core::slice::Iter<'_, AccountInfo<'_>> = &mut accounts.iter();
-    let payer_account = next_account_info(accounts_iter)?;
-    let authority_account: &AccountInfo<'_> = next_account_info(accounts_iter)?;
-    let mint_account = next_account_info(accounts_iter)?;
-    let info_account = next_account_info(accounts_iter)?;
-    let system_program = next_account_info(accounts_iter)?;
-    let fee_receiver = next_account_info(accounts_iter)?;
+    let mint = token::read_mint(mint_account)?;
+    msg!(
+        "[CreateInfo] Mint decimals: {}, supply: {}",
+        mint.decimals,
+        mint.supply
+    );
 
-    msg!("[CreateInfo] Validating signer and authority");
-    if !payer_account.is_signer {
-        msg!("[Error] Payer is not signer");
-        return Err(ProgramError::MissingRequiredSignature);
+    for link in &args.links {
+        msg!("[CreateInfo] Adding link: {} -> {}", link.label, link.url);
     }
 
-    if !authority_account.is_signer {
-        msg!("[Error] Authority is not signer");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let symbol = check_search_key(args.symbol.as_deref(), "Symbol")?;
+    let name = check_search_key(args.name.as_deref(), "Name")?;
 
-    if authority_account.key != &AUTHORITY {
-        msg!(
-            "[Error] Invalid authority account: {:?}",
-            authority_account.key
-        );
-        return Err(ProgramError::InvalidArgument);
-    }
+    let images = Images {
+        icon: args.icon_uri,
+        header: args.header_uri,
+    };
 
-    if fee_receiver.key != &FEE_RECEIVER {
-        msg!("[Error] Invalid fee receiver: {:?}", fee_receiver.key);
-        return Err(ProgramError::InvalidArgument);
+    Ok(TokenInfoV2 {
+        mint: *mint_account.key,
+        creator: *creator,
+        payer: *payer,
+        creation_timestamp: ts,
+        creation_slot: clock.slot,
+        update_timestamp: ts,
+        mint_decimals: mint.decimals,
+        mint_supply_at_creation: mint.supply,
+        verified: false,
+        verified_at: 0,
+        challenged: false,
+        reserved_bytes: args.reserve_bytes,
+        endorsements: 0,
+        symbol,
+        name,
+        origin: args.origin,
+        description_format: args.description_format,
+        timeline: LaunchTimeline::default(),
+        description: args.description,
+        links: args.links,
+        images,
+        image_metadata: args.image_metadata,
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CreateMode {
+    /// Transfer the fee from the payer.
+    Pay,
+    /// Return a `CreateQuote` without writing anything.
+    Preview,
+    /// The fee was transferred by the previous instruction.
+    Prepaid,
+}
+
+fn process_create_info(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateInfoArgs,
+    mode: CreateMode,
+) -> ProgramResult {
+    msg!("[CreateInfo] Starting token info creation (V2)");
+
+    let ctx = validation::CreateInfo::load(program_id, accounts, mode == CreateMode::Prepaid)?;
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::fee_receiver(ctx.fee_receiver, &config)?;
+    validation::create_authority(ctx.authority, &config)?;
+    msg!(
+        "[CreateInfo] Payer: {:?}, creator: {:?}, info account bump: {}",
+        ctx.payer.key,
+        ctx.creator.key,
+        ctx.info_bump
+    );
+
+    check_description_format(&config, args.description_format)?;
+    check_ipfs_uris(&args)?;
+    check_image_metadata(&args.image_metadata)?;
+    let operator = match &args.origin {
+        Some(origin) => {
+            validation::origin(accounts, origin)?;
+            validation::operator(program_id, accounts, &ctx.tenant, origin)?
+        }
+        None => None,
+    };
+    check_allow_lists(&config, ctx.mint)?;
+    let reserve_bytes = args.reserve_bytes;
+    let record = TokenInfo::V2(build_info(ctx.mint, ctx.creator.key, ctx.payer.key, args)?);
+    let account_size = record_len(&record)?.saturating_add(reserve_bytes as usize);
+    check_account_size(&config, account_size)?;
+
+    let quota = check_creation_quota(program_id, ctx.quota, ctx.creator.key, &config)?;
+    let stats = load_payer_fee_stats(program_id, &ctx)?;
+    let base_fee = base_fee(&config, ctx.price_update)?;
+    let fee = config.discounted_fee(base_fee, stats.map_or(0, |stats| stats.paid_creations));
+
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(account_size);
+    let fee_stats_lamports = match stats {
+        Some(_) => 0,
+        None => rent.minimum_balance(FeeStats::LEN),
+    };
+    let quota_lamports = match quota {
+        Some(_) if ctx.quota.data_is_empty() => rent.minimum_balance(CreationQuota::LEN),
+        _ => 0,
+    };
+    let receipt_lamports = rent.minimum_balance(Receipt::LEN);
+    let operator_share = operator.map_or(0, |(operator, _)| operator.share_of(fee));
+    // A prepaid transfer only covers the fee receiver's part.
+    let payer_fee = if mode == CreateMode::Prepaid {
+        operator_share
+    } else {
+        fee
+    };
+    let required = checked_sum(&[
+        payer_fee,
+        lamports,
+        fee_stats_lamports,
+        quota_lamports,
+        receipt_lamports,
+        TX_FEE_HEADROOM,
+    ])?;
+    let available = ctx.payer.lamports();
+    msg!(
+        "[CreateInfo] Checking payer balance >= {} (fee {} + rent {} + stats rent {} + quota rent {} + receipt rent {} + headroom {})",
+        required,
+        payer_fee,
+        lamports,
+        fee_stats_lamports,
+        quota_lamports,
+        receipt_lamports,
+        TX_FEE_HEADROOM
+    );
+    if mode == CreateMode::Preview {
+        let quote = CreateQuote {
+            account_size: account_size as u64,
+            fee,
+            rent: lamports,
+            fee_stats_rent: fee_stats_lamports,
+            quota_rent: quota_lamports,
+            receipt_rent: receipt_lamports,
+            required,
+            available,
+        };
+        msg!("[PreviewCreateInfo] {:?}", quote);
+        set_return_data(&borsh::to_vec(&quote)?);
+        return Ok(());
+    }
+    if available < required {
+        msg!(
+            "[Error] Insufficient funds: has {}, needs {}",
+            available,
+            required
+        );
+        return Err(TokenInfoError::InsufficientFunds {
+            required,
+            available,
+        }
+        .into());
+    }
+
+    let treasury_fee = fee - operator_share;
+    if let Some(instructions_sysvar) = ctx.instructions_sysvar {
+        check_prepaid_fee(instructions_sysvar, ctx.fee_receiver.key, treasury_fee)?;
+    } else {
+        msg!("[CreateInfo] Transferring fee to receiver");
+        invoke(
+            &system_instruction::transfer(ctx.payer.key, ctx.fee_receiver.key, treasury_fee),
+            &[
+                ctx.payer.clone(),
+                ctx.fee_receiver.clone(),
+                ctx.system_program.clone(),
+            ],
+        )?;
+    }
+    if let Some((operator, payout)) = operator.filter(|_| operator_share > 0) {
+        msg!(
+            "[CreateInfo] Routing {} of the fee to operator {:?}",
+            operator_share,
+            operator.operator
+        );
+        invoke(
+            &system_instruction::transfer(ctx.payer.key, payout.key, operator_share),
+            &[
+                ctx.payer.clone(),
+                payout.clone(),
+                ctx.system_program.clone(),
+            ],
+        )?;
+    }
+    record_fee(program_id, &ctx, stats, fee, fee_stats_lamports)?;
+    store_receipt(program_id, &ctx, fee, receipt_lamports)?;
+    if let Some(quota) = quota {
+        store_creation_quota(
+            program_id,
+            &ctx.tenant,
+            ctx.quota,
+            ctx.quota_bump,
+            ctx.payer,
+            None,
+            ctx.system_program,
+            &quota,
+            quota_lamports,
+        )?;
     }
 
-    let fee_amount = 100_000_000;
-    msg!("[CreateInfo] Checking payer balance >= {}", fee_amount);
-    if payer_account.lamports() < fee_amount {
+    msg!(
+        "[CreateInfo] Creating account with rent exemption: {} lamports",
+        lamports
+    );
+
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.payer.key,
+            ctx.info.key,
+            lamports,
+            account_size as u64,
+            program_id,
+        ),
+        &[
+            ctx.payer.clone(),
+            ctx.info.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[
+            b"token_info",
+            tenant_seed(&ctx.tenant),
+            ctx.mint.key.as_ref(),
+            &[ctx.info_bump],
+        ]],
+    )?;
+
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &record)?;
+    msg!("[CreateInfo] Token info account created and data written successfully");
+    config.total_creates = config.total_creates.saturating_add(1);
+    store_config(ctx.config, &config)?;
+
+    announce_created(
+        &ctx.tenant,
+        ctx.mint.key,
+        ctx.creator.key,
+        ctx.info.key,
+        ctx.info_bump,
+    )
+}
+
+fn process_create_info_legacy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateInfoArgs,
+) -> ProgramResult {
+    msg!("[CreateInfo] Starting token info creation (legacy payload)");
+
+    let ctx = validation::CreateInfoLegacy::load(program_id, accounts)?;
+    let mut config = ctx
+        .config
+        .map(|config| load_config(program_id, config))
+        .transpose()?;
+    let fee = match &config {
+        Some(config) => {
+            check_v1_cutover(config)?;
+            validation::fee_receiver(ctx.fee_receiver, config)?;
+            validation::create_authority(ctx.authority, config)?;
+            check_description_format(config, args.description_format)?;
+            check_allow_lists(config, ctx.mint)?;
+            base_fee(config, ctx.price_update)?
+        }
+        None => {
+            validation::authority(ctx.authority)?;
+            validation::key(ctx.fee_receiver, &FEE_RECEIVER, "Fee receiver")?;
+            FEE_AMOUNT
+        }
+    };
+    check_ipfs_uris(&args)?;
+
+    let record = TokenInfo::V2(build_info(ctx.mint, ctx.payer.key, ctx.payer.key, args)?);
+    let account_size = record_len(&record)?;
+    if let Some(config) = &config {
+        check_account_size(config, account_size)?;
+    }
+    let lamports = Rent::get()?.minimum_balance(account_size);
+    let required = checked_sum(&[fee, lamports, TX_FEE_HEADROOM])?;
+    let available = ctx.payer.lamports();
+    msg!(
+        "[CreateInfo] Checking payer balance >= {} (fee {} + rent {} + headroom {})",
+        required,
+        fee,
+        lamports,
+        TX_FEE_HEADROOM
+    );
+    if available < required {
         msg!(
             "[Error] Insufficient funds: has {}, needs {}",
-            payer_account.lamports(),
-            fee_amount
+            available,
+            required
         );
-        return Err(TokenInfoError::InsufficientFunds.into());
+        return Err(TokenInfoError::InsufficientFunds {
+            required,
+            available,
+        }
+        .into());
     }
 
     msg!("[CreateInfo] Transferring fee to receiver");
     invoke(
-        &system_instruction::transfer(payer_account.key, fee_receiver.key, fee_amount),
+        &system_instruction::transfer(ctx.payer.key, ctx.fee_receiver.key, fee),
         &[
-            payer_account.clone(),
-            fee_receiver.clone(),
-            system_program.clone(),
+            ctx.payer.clone(),
+            ctx.fee_receiver.clone(),
+            ctx.system_program.clone(),
+        ],
+    )?;
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.payer.key,
+            ctx.info.key,
+            lamports,
+            account_size as u64,
+            program_id,
+        ),
+        &[
+            ctx.payer.clone(),
+            ctx.info.clone(),
+            ctx.system_program.clone(),
         ],
+        &[&[
+            b"token_info",
+            tenant_seed(&ctx.tenant),
+            ctx.mint.key.as_ref(),
+            &[ctx.info_bump],
+        ]],
     )?;
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &record)?;
+    msg!("[CreateInfo] Token info account created and data written successfully");
+    if let (Some(config_account), Some(config)) = (ctx.config, &mut config) {
+        config.total_creates = config.total_creates.saturating_add(1);
+        store_config(config_account, config)?;
+    }
+
+    announce_created(
+        &ctx.tenant,
+        ctx.mint.key,
+        ctx.payer.key,
+        ctx.info.key,
+        ctx.info_bump,
+    )
+}
+
+/// Bincode tag of `SystemInstruction::Transfer`.
+const SYSTEM_TRANSFER_TAG: [u8; 4] = 2u32.to_le_bytes();
+
+/// The instruction right before this one must be a system transfer of at least
+/// `fee` to the fee receiver. Only top-level instructions are visible here, so
+/// a payment made through CPI doesn't qualify, and requiring the adjacent
+/// instruction keeps one transfer from paying for two creates. The create
+/// itself must be top-level too: a CPI caller could otherwise invoke it many
+/// times behind the same transfer.
+fn check_prepaid_fee(
+    instructions_sysvar: &AccountInfo,
+    fee_receiver: &Pubkey,
+    fee: u64,
+) -> ProgramResult {
+    if get_stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT {
+        msg!("[Error] Prepaid create must be a top-level instruction");
+        return Err(TokenInfoError::FeeNotPrepaid { fee }.into());
+    }
+    let current = instructions::load_current_index_checked(instructions_sysvar)?;
+    let paid = match current.checked_sub(1) {
+        Some(index) => {
+            let payment =
+                instructions::load_instruction_at_checked(index as usize, instructions_sysvar)?;
+            let data = &payment.data;
+            if system_program::check_id(&payment.program_id)
+                && data.len() == 12
+                && data[..4] == SYSTEM_TRANSFER_TAG
+                && payment.accounts.get(1).map(|meta| &meta.pubkey) == Some(fee_receiver)
+            {
+                u64::from_le_bytes(data[4..].try_into().unwrap())
+            } else {
+                0
+            }
+        }
+        None => 0,
+    };
+
+    if paid < fee {
+        msg!(
+            "[Error] Previous instruction pays {} lamports to the fee receiver, fee is {}",
+            paid,
+            fee
+        );
+        return Err(TokenInfoError::FeeNotPrepaid { fee }.into());
+    }
+    msg!("[CreateInfo] Fee of {} prepaid ({} transferred)", fee, paid);
+    Ok(())
+}
+
+fn announce_created(
+    tenant: &Pubkey,
+    mint: &Pubkey,
+    creator: &Pubkey,
+    info_account: &Pubkey,
+    bump: u8,
+) -> ProgramResult {
+    let created = CreatedInfo {
+        info_account: *info_account,
+        bump,
+    };
+    msg!("[CreateInfo] {:?}", created);
+    set_return_data(&borsh::to_vec(&created)?);
+
+    events::emit(&events::Event::InfoCreated {
+        tenant: *tenant,
+        mint: *mint,
+        creator: *creator,
+        info_account: *info_account,
+        bump,
+    })
+}
+
+/// Sum of lamport amounts; the fee can come from an admin setting or the
+/// oracle, so it isn't trusted to stay small.
+fn checked_sum(amounts: &[u64]) -> Result<u64, ProgramError> {
+    amounts
+        .iter()
+        .try_fold(0u64, |sum, &amount| sum.checked_add(amount))
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// `FEE_AMOUNT`, or the configured USD amount converted at the current
+/// SOL/USD price.
+fn base_fee(config: &Config, price_update: Option<&AccountInfo>) -> Result<u64, ProgramError> {
+    let Some(usd_fee) = config.usd_fee else {
+        return Ok(FEE_AMOUNT);
+    };
+    let Some(price_update) = price_update else {
+        msg!("[Error] USD fee mode needs a price update account");
+        return Err(TokenInfoError::InvalidPriceFeed.into());
+    };
 
-    let (expected_info_address, bump_seed) = find_info_account(mint_account.key, program_id);
+    let price = oracle::read_price_update(price_update)?;
+    if price.feed_id != usd_fee.feed_id {
+        msg!("[Error] Price update is for a different feed");
+        return Err(TokenInfoError::InvalidPriceFeed.into());
+    }
+
+    let now = clock::Clock::get()?.unix_timestamp;
+    if now.saturating_sub(price.publish_time) > usd_fee.max_staleness_secs {
+        msg!(
+            "[Error] Price published at {} is stale, now {}",
+            price.publish_time,
+            now
+        );
+        return Err(TokenInfoError::StalePrice {
+            publish_time: price.publish_time,
+            now,
+        }
+        .into());
+    }
+
+    let Some(lamports) = oracle::micro_usd_to_lamports(usd_fee.micro_usd, &price) else {
+        msg!("[Error] Unusable price {}e{}", price.price, price.exponent);
+        return Err(TokenInfoError::InvalidPriceFeed.into());
+    };
+    let conf_bps = (price.conf as u128 * BPS_DENOMINATOR as u128 / price.price as u128)
+        .min(u64::MAX as u128) as u64;
+    if conf_bps > usd_fee.max_confidence_bps as u64 {
+        msg!(
+            "[Error] Price confidence {} bps wider than {} bps",
+            conf_bps,
+            usd_fee.max_confidence_bps
+        );
+        return Err(TokenInfoError::PriceConfidenceTooWide {
+            conf_bps,
+            max_bps: usd_fee.max_confidence_bps,
+        }
+        .into());
+    }
+
+    let fee = lamports.clamp(usd_fee.min_lamports, usd_fee.max_lamports);
     msg!(
-        "[CreateInfo] Derived info account: {:?}, bump: {}",
-        expected_info_address,
-        bump_seed
+        "[Fee] {} micro-USD at {}e{} = {} lamports, charging {}",
+        usd_fee.micro_usd,
+        price.price,
+        price.exponent,
+        lamports,
+        fee
     );
+    Ok(fee)
+}
+
+/// The creator's quota record with this create counted, or `None` when no
+/// quota applies.
+fn check_creation_quota(
+    program_id: &Pubkey,
+    quota_account: &AccountInfo,
+    creator: &Pubkey,
+    config: &Config,
+) -> Result<Option<CreationQuota>, ProgramError> {
+    if config.creator_daily_quota == 0 || *creator == config.admin {
+        return Ok(None);
+    }
+
+    let day = clock::Clock::get()?.unix_timestamp / SECONDS_PER_DAY;
+    let mut quota = if quota_account.data_is_empty() {
+        CreationQuota {
+            wallet: *creator,
+            day,
+            created: 0,
+        }
+    } else {
+        load_creation_quota(program_id, quota_account)?
+    };
+    if quota.day != day {
+        quota.day = day;
+        quota.created = 0;
+    }
 
-    if expected_info_address != *info_account.key {
+    if quota.created >= config.creator_daily_quota {
         msg!(
-            "[Error] Info account mismatch. Expected: {:?}, got: {:?}",
-            expected_info_address,
-            info_account.key
+            "[Error] Creator {:?} reached the daily quota of {}",
+            creator,
+            config.creator_daily_quota
         );
-        return Err(ProgramError::InvalidArgument);
+        return Err(TokenInfoError::QuotaExceeded {
+            quota: config.creator_daily_quota,
+        }
+        .into());
     }
+    quota.created += 1;
+    Ok(Some(quota))
+}
 
-    if !info_account.data_is_empty() {
-        msg!("[Error] Info account already initialized");
-        return Err(TokenInfoError::AccountAlreadyExists.into());
-    }
+fn load_creation_quota(
+    program_id: &Pubkey,
+    account: &AccountInfo,
+) -> Result<CreationQuota, ProgramError> {
+    validation::state(
+        account,
+        program_id,
+        CREATION_QUOTA_MAGIC_BYTE,
+        Some(CREATION_QUOTA_VERSION),
+        "Creation quota account",
+    )?;
+    let data = account.data.borrow();
+    CreationQuota::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
 
-    if *info_account.owner != *system_program.key {
-        msg!("[Error] Info account owner mismatch. Expected system program");
-        return Err(ProgramError::InvalidAccountData);
+/// Creates the quota account on the first counted create, funded by `payer`,
+/// which signs with `payer_seeds` when it is a PDA.
+#[allow(clippy::too_many_arguments)]
+fn store_creation_quota<'info>(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    quota_account: &AccountInfo<'info>,
+    quota_bump: u8,
+    payer: &AccountInfo<'info>,
+    payer_seeds: Option<&[&[u8]]>,
+    system_program: &AccountInfo<'info>,
+    quota: &CreationQuota,
+    rent_lamports: u64,
+) -> ProgramResult {
+    if quota_account.data_is_empty() {
+        let quota_seeds: &[&[u8]] = &[
+            b"creation_quota",
+            tenant_seed(tenant),
+            quota.wallet.as_ref(),
+            &[quota_bump],
+        ];
+        let signers = match payer_seeds {
+            Some(payer_seeds) => vec![payer_seeds, quota_seeds],
+            None => vec![quota_seeds],
+        };
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                quota_account.key,
+                rent_lamports,
+                CreationQuota::LEN as u64,
+                program_id,
+            ),
+            &[payer.clone(), quota_account.clone(), system_program.clone()],
+            &signers,
+        )?;
     }
 
-    let clock = clock::Clock::get()?;
-    let ts = clock.unix_timestamp;
-    msg!("[CreateInfo] Timestamp: {}", ts);
+    write_record(
+        quota_account,
+        CREATION_QUOTA_MAGIC_BYTE,
+        CREATION_QUOTA_VERSION,
+        &quota,
+    )?;
+    msg!(
+        "[CreateInfo] Creator has created {} records today",
+        quota.created
+    );
 
-    for link in &links {
-        msg!("[CreateInfo] Adding link: {} -> {}", link.label, link.url);
+    Ok(())
+}
+
+// Callers are expected to have checked the address and owner through the
+// validation layer.
+/// `None` until the payer's first paid create.
+fn load_payer_fee_stats(
+    program_id: &Pubkey,
+    ctx: &validation::CreateInfo,
+) -> Result<Option<FeeStats>, ProgramError> {
+    if ctx.fee_stats.data_is_empty() {
+        return Ok(None);
     }
+    load_fee_stats(program_id, ctx.fee_stats).map(Some)
+}
 
-    let images = Images {
-        icon: icon_uri.clone(),
-        header: header_uri.clone(),
+/// Adds `amount` to the payer's fee stats, creating the account on their
+/// first paid create.
+fn record_fee(
+    program_id: &Pubkey,
+    ctx: &validation::CreateInfo,
+    stats: Option<FeeStats>,
+    amount: u64,
+    rent_lamports: u64,
+) -> ProgramResult {
+    let now = clock::Clock::get()?.unix_timestamp;
+    let stats = if let Some(mut stats) = stats {
+        stats.total_fees_paid = stats.total_fees_paid.saturating_add(amount);
+        stats.paid_creations = stats.paid_creations.saturating_add(1);
+        stats.last_paid_at = now;
+        stats
+    } else {
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.payer.key,
+                ctx.fee_stats.key,
+                rent_lamports,
+                FeeStats::LEN as u64,
+                program_id,
+            ),
+            &[
+                ctx.payer.clone(),
+                ctx.fee_stats.clone(),
+                ctx.system_program.clone(),
+            ],
+            &[&[
+                b"fee_stats",
+                tenant_seed(&ctx.tenant),
+                ctx.payer.key.as_ref(),
+                &[ctx.fee_stats_bump],
+            ]],
+        )?;
+        FeeStats {
+            payer: *ctx.payer.key,
+            total_fees_paid: amount,
+            paid_creations: 1,
+            first_paid_at: now,
+            last_paid_at: now,
+        }
     };
 
-    let info_v1 = TokenInfoV1 {
-        mint: mint_account.key.to_string(),
-        description: description.clone(),
-        links,
-        images,
-        creation_timestamp: ts,
-        update_timestamp: ts,
+    write_record(
+        ctx.fee_stats,
+        FEE_STATS_MAGIC_BYTE,
+        FEE_STATS_VERSION,
+        &stats,
+    )?;
+    msg!(
+        "[CreateInfo] Payer has paid {} lamports over {} creates",
+        stats.total_fees_paid,
+        stats.paid_creations
+    );
+
+    Ok(())
+}
+
+fn store_receipt(
+    program_id: &Pubkey,
+    ctx: &validation::CreateInfo,
+    fee: u64,
+    rent_lamports: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.payer.key,
+            ctx.receipt.key,
+            rent_lamports,
+            Receipt::LEN as u64,
+            program_id,
+        ),
+        &[
+            ctx.payer.clone(),
+            ctx.receipt.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[
+            b"receipt",
+            tenant_seed(&ctx.tenant),
+            ctx.payer.key.as_ref(),
+            ctx.mint.key.as_ref(),
+            &[ctx.receipt_bump],
+        ]],
+    )?;
+
+    let receipt = Receipt {
+        payer: *ctx.payer.key,
+        mint: *ctx.mint.key,
+        info_account: *ctx.info.key,
+        fee,
+        slot: clock::Clock::get()?.slot,
+        refunded: 0,
     };
+    write_record(ctx.receipt, RECEIPT_MAGIC_BYTE, RECEIPT_VERSION, &receipt)
+}
+
+fn load_receipt(program_id: &Pubkey, receipt: &AccountInfo) -> Result<Receipt, ProgramError> {
+    validation::state(
+        receipt,
+        program_id,
+        RECEIPT_MAGIC_BYTE,
+        Some(RECEIPT_VERSION),
+        "Receipt account",
+    )?;
+    let data = receipt.data.borrow();
+    Receipt::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
 
-    let info: TokenInfo = TokenInfo::V1(info_v1);
+fn load_fee_stats(program_id: &Pubkey, fee_stats: &AccountInfo) -> Result<FeeStats, ProgramError> {
+    validation::state(
+        fee_stats,
+        program_id,
+        FEE_STATS_MAGIC_BYTE,
+        Some(FEE_STATS_VERSION),
+        "Fee stats account",
+    )?;
+    let data = fee_stats.data.borrow();
+    FeeStats::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
 
-    let mut serialized_data = Vec::with_capacity(1024);
-    serialized_data.push(MAGIC_BYTE);
-    serialized_data.push(DATA_VERSION);
-    info.serialize(&mut serialized_data)?;
+fn load_config(program_id: &Pubkey, config_account: &AccountInfo) -> Result<Config, ProgramError> {
+    validation::state(
+        config_account,
+        program_id,
+        CONFIG_MAGIC_BYTE,
+        Some(CONFIG_VERSION),
+        "Config account",
+    )?;
+    let data = config_account.data.borrow();
+    Config::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
 
-    let rent = Rent::get()?;
-    let lamports = rent.minimum_balance(serialized_data.len());
+fn check_account_size(config: &Config, size: usize) -> ProgramResult {
+    if size > config.max_account_size as usize {
+        msg!(
+            "[Error] Account too large: {} bytes, max {}",
+            size,
+            config.max_account_size
+        );
+        return Err(TokenInfoError::AccountTooLarge {
+            size: size as u64,
+            max: config.max_account_size,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn check_description_format(config: &Config, format: DescriptionFormat) -> ProgramResult {
+    if config.allowed_description_formats & format.mask() == 0 {
+        msg!("[Error] Description format {:?} not allowed", format);
+        return Err(TokenInfoError::DescriptionFormatNotAllowed.into());
+    }
+    Ok(())
+}
+
+fn check_ipfs_uris(args: &CreateInfoArgs) -> ProgramResult {
+    let uris = [&args.icon_uri, &args.header_uri]
+        .into_iter()
+        .chain(args.links.iter().map(|link| &link.url));
+    for uri in uris {
+        check_ipfs_uri(uri)?;
+    }
+    Ok(())
+}
+
+fn check_ipfs_uri(uri: &str) -> ProgramResult {
+    if !ipfs::is_valid_ipfs_uri(uri) {
+        msg!("[Error] Malformed IPFS CID in uri: {}", uri);
+        return Err(TokenInfoError::InvalidIpfsCid.into());
+    }
+    Ok(())
+}
+
+fn check_search_key<const N: usize>(
+    value: Option<&str>,
+    name: &str,
+) -> Result<[u8; N], ProgramError> {
+    let Some(value) = value else {
+        return Ok([0; N]);
+    };
+    search_key(value).ok_or_else(|| {
+        msg!(
+            "[Error] {} must be 1 to {} bytes once uppercased: {}",
+            name,
+            N,
+            value
+        );
+        TokenInfoError::InvalidSearchKey.into()
+    })
+}
+
+fn check_image_metadata(image_metadata: &ImagesMetadata) -> ProgramResult {
+    for metadata in [&image_metadata.icon, &image_metadata.header] {
+        if let Some(mime) = &metadata.mime {
+            if mime.len() > MAX_MIME_LEN || !mime.starts_with("image/") {
+                msg!("[Error] Invalid image MIME type: {}", mime);
+                return Err(TokenInfoError::InvalidImageMetadata.into());
+            }
+        }
+        if metadata.width == Some(0) || metadata.height == Some(0) {
+            msg!("[Error] Image dimensions must be non-zero");
+            return Err(TokenInfoError::InvalidImageMetadata.into());
+        }
+    }
+    Ok(())
+}
+
+fn check_allow_lists(config: &Config, mint_account: &AccountInfo) -> ProgramResult {
+    if !config.allowed_token_programs.is_empty()
+        && !config.allowed_token_programs.contains(mint_account.owner)
+    {
+        msg!("[Error] Token program {:?} not allowed", mint_account.owner);
+        return Err(TokenInfoError::TokenProgramNotAllowed.into());
+    }
+
+    if !config.allowed_mints.is_empty() && !config.allowed_mints.contains(mint_account.key) {
+        msg!("[Error] Mint {:?} not allowed", mint_account.key);
+        return Err(TokenInfoError::MintNotAllowed.into());
+    }
+
+    Ok(())
+}
+
+fn store_config(config_account: &AccountInfo, config: &Config) -> ProgramResult {
+    check_config(config)?;
+    write_record(config_account, CONFIG_MAGIC_BYTE, CONFIG_VERSION, config)
+}
+
+fn process_init_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    sponsorship_daily_cap: u64,
+) -> ProgramResult {
+    msg!("[InitConfig] Initializing program config");
+
+    let ctx = validation::InitConfig::load(program_id, accounts)?;
+
+    let config = new_config(
+        *ctx.authority.key,
+        sponsorship_daily_cap,
+        FEE_RECEIVER,
+        GLOBAL_TENANT,
+        None,
+    );
+    create_config(
+        program_id,
+        ctx.payer,
+        ctx.config,
+        ctx.system_program,
+        &config,
+    )?;
     msg!(
-        "[CreateInfo] Creating account with rent exemption: {} lamports",
-        lamports
+        "[InitConfig] Config created with sponsorship daily cap {}",
+        sponsorship_daily_cap
+    );
+
+    Ok(())
+}
+
+fn process_init_tenant_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    sponsorship_daily_cap: u64,
+    fee_receiver: Pubkey,
+) -> ProgramResult {
+    let ctx = validation::InitTenantConfig::load(program_id, accounts)?;
+    msg!(
+        "[InitTenantConfig] Initializing config for tenant {:?}",
+        ctx.tenant.key
+    );
+
+    let config = new_config(
+        *ctx.tenant.key,
+        sponsorship_daily_cap,
+        fee_receiver,
+        *ctx.tenant.key,
+        Some(*ctx.tenant.key),
+    );
+    create_config(
+        program_id,
+        ctx.payer,
+        ctx.config,
+        ctx.system_program,
+        &config,
+    )?;
+    msg!(
+        "[InitTenantConfig] Config created with fee receiver {:?}",
+        fee_receiver
     );
 
+    Ok(())
+}
+
+fn new_config(
+    admin: Pubkey,
+    sponsorship_daily_cap: u64,
+    fee_receiver: Pubkey,
+    tenant: Pubkey,
+    authority: Option<Pubkey>,
+) -> Config {
+    Config {
+        admin,
+        sponsorship_daily_cap,
+        sponsorship_day: 0,
+        sponsorship_spent_today: 0,
+        max_account_size: DEFAULT_MAX_ACCOUNT_SIZE,
+        allowed_description_formats: DescriptionFormat::ALL,
+        allowed_token_programs: Vec::new(),
+        allowed_mints: Vec::new(),
+        fee_receiver,
+        fee_receiver_delay: DEFAULT_FEE_RECEIVER_DELAY,
+        pending_fee_receiver: None,
+        admin_delay: 0,
+        next_action_id: 0,
+        loyalty_tiers: Config::default_loyalty_tiers(),
+        usd_fee: None,
+        tenant,
+        creator_daily_quota: 0,
+        link_attester: None,
+        v1_cutover_slot: None,
+        verification_dispute_delay: 0,
+        challenge_bond: FEE_AMOUNT,
+        listing_bond_lock: DEFAULT_LISTING_BOND_LOCK,
+        total_creates: 0,
+        sponsored_creates: 0,
+        authority,
+    }
+}
+
+fn create_config<'info>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'info>,
+    config_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Config,
+) -> ProgramResult {
+    check_config(config)?;
+    let (_, bump) = find_config_account(&config.tenant, program_id);
+
+    let account_size = record_len(config)?;
+
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(account_size);
+
     invoke_signed(
         &system_instruction::create_account(
-            payer_account.key,
-            info_account.key,
+            payer.key,
+            config_account.key,
             lamports,
-            serialized_data.len() as u64,
+            account_size as u64,
             program_id,
         ),
         &[
-            payer_account.clone(),
-            info_account.clone(),
+            payer.clone(),
+            config_account.clone(),
             system_program.clone(),
         ],
-        &[&[b"token_info", mint_account.key.as_ref(), &[bump_seed]]],
+        &[&[b"config", tenant_seed(&config.tenant), &[bump]]],
     )?;
 
-    info_account
-        .data
-        .borrow_mut()
-        .copy_from_slice(&serialized_data);
-    msg!("[CreateInfo] Token info account created and data written successfully");
+    write_record(config_account, CONFIG_MAGIC_BYTE, CONFIG_VERSION, config)?;
 
     Ok(())
 }
+
+fn process_set_sponsorship_cap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    daily_cap: u64,
+) -> ProgramResult {
+    let ctx = validation::AdminConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetSponsorshipCap { daily_cap });
+    store_config(ctx.config, &config)
+}
+
+fn process_fund_sponsorship(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let ctx = validation::FundSponsorship::load(program_id, accounts)?;
+
+    msg!("[FundSponsorship] Depositing {} lamports", amount);
+    invoke(
+        &system_instruction::transfer(ctx.funder.key, ctx.vault.key, amount),
+        &[
+            ctx.funder.clone(),
+            ctx.vault.clone(),
+            ctx.system_program.clone(),
+        ],
+    )
+}
+
+fn process_create_info_sponsored(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateInfoArgs,
+) -> ProgramResult {
+    msg!("[CreateInfoSponsored] Starting sponsored token info creation");
+
+    let ctx = validation::CreateInfoSponsored::load(program_id, accounts)?;
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::fee_receiver(ctx.fee_receiver, &config)?;
+    validation::create_authority(ctx.authority, &config)?;
+
+    check_description_format(&config, args.description_format)?;
+    check_ipfs_uris(&args)?;
+    check_image_metadata(&args.image_metadata)?;
+    if let Some(origin) = &args.origin {
+        validation::origin(accounts, origin)?;
+    }
+    check_allow_lists(&config, ctx.mint)?;
+    let reserve_bytes = args.reserve_bytes;
+    let record = TokenInfo::V2(build_info(ctx.mint, ctx.creator.key, ctx.vault.key, args)?);
+    let account_size = record_len(&record)?.saturating_add(reserve_bytes as usize);
+    check_account_size(&config, account_size)?;
+
+    let quota = check_creation_quota(program_id, ctx.quota, ctx.creator.key, &config)?;
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(account_size);
+    let quota_lamports = match quota {
+        Some(_) if ctx.quota.data_is_empty() => rent.minimum_balance(CreationQuota::LEN),
+        _ => 0,
+    };
+    let fee = base_fee(&config, ctx.price_update)?;
+    let cost = checked_sum(&[fee, lamports, quota_lamports])?;
+    charge_sponsorship(&mut config, cost)?;
+
+    // The vault is a system account, so it has to stay rent exempt itself.
+    let required = checked_sum(&[cost, rent.minimum_balance(0)])?;
+    let available = ctx.vault.lamports();
+    if available < required {
+        msg!(
+            "[Error] Sponsorship vault underfunded: has {}, needs {}",
+            available,
+            required
+        );
+        return Err(TokenInfoError::InsufficientFunds {
+            required,
+            available,
+        }
+        .into());
+    }
+
+    config.total_creates = config.total_creates.saturating_add(1);
+    config.sponsored_creates = config.sponsored_creates.saturating_add(1);
+    store_config(ctx.config, &config)?;
+
+    let vault_seeds: &[&[u8]] = &[
+        b"sponsorship_vault",
+        tenant_seed(&ctx.tenant),
+        &[ctx.vault_bump],
+    ];
+
+    msg!("[CreateInfoSponsored] Transferring fee from sponsorship vault");
+    invoke_signed(
+        &system_instruction::transfer(ctx.vault.key, ctx.fee_receiver.key, fee),
+        &[
+            ctx.vault.clone(),
+            ctx.fee_receiver.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    msg!(
+        "[CreateInfoSponsored] Creating account with rent exemption: {} lamports",
+        lamports
+    );
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.vault.key,
+            ctx.info.key,
+            lamports,
+            account_size as u64,
+            program_id,
+        ),
+        &[
+            ctx.vault.clone(),
+            ctx.info.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[
+            vault_seeds,
+            &[
+                b"token_info",
+                tenant_seed(&ctx.tenant),
+                ctx.mint.key.as_ref(),
+                &[ctx.info_bump],
+            ],
+        ],
+    )?;
+
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &record)?;
+    if let Some(quota) = quota {
+        store_creation_quota(
+            program_id,
+            &ctx.tenant,
+            ctx.quota,
+            ctx.quota_bump,
+            ctx.vault,
+            Some(vault_seeds),
+            ctx.system_program,
+            &quota,
+            quota_lamports,
+        )?;
+    }
+    msg!(
+        "[CreateInfoSponsored] Token info account created; sponsorship spent today: {}",
+        config.sponsorship_spent_today
+    );
+
+    announce_created(
+        &ctx.tenant,
+        ctx.mint.key,
+        ctx.creator.key,
+        ctx.info.key,
+        ctx.info_bump,
+    )
+}
+
+fn process_set_max_account_size(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_account_size: u32,
+) -> ProgramResult {
+    let ctx = validation::AdminConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(
+        &mut config,
+        &AdminAction::SetMaxAccountSize { max_account_size },
+    );
+    store_config(ctx.config, &config)
+}
+
+fn process_set_allowed_description_formats(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    formats: Vec<DescriptionFormat>,
+) -> ProgramResult {
+    let ctx = validation::AdminConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(
+        &mut config,
+        &AdminAction::SetAllowedDescriptionFormats { formats },
+    );
+    store_config(ctx.config, &config)
+}
+
+fn load_info(program_id: &Pubkey, info_account: &AccountInfo) -> Result<TokenInfoV2, ProgramError> {
+    // Any data version: V1 records are reported as legacy below.
+    validation::state(info_account, program_id, MAGIC_BYTE, None, "Info account")?;
+    let data = info_account.data.borrow();
+
+    match TokenInfo::from_account_data(&data) {
+        Some(TokenInfo::V2(info)) => Ok(info),
+        Some(TokenInfo::V1(_)) => {
+            msg!("[Error] Legacy V1 record can't be modified");
+            Err(TokenInfoError::LegacyRecord.into())
+        }
+        None => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Rewrites a V2 record in place, growing the account (funded by `payer`)
+/// only when the new encoding no longer fits in it, reserved headroom
+/// included.
+fn store_info<'info>(
+    info_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Config,
+    previous: &TokenInfoV2,
+    info: TokenInfoV2,
+) -> ProgramResult {
+    let changed_fields = events::changed_fields(previous, &info);
+    let mint = info.mint;
+    let old_hash = record_hash(&TokenInfo::V2(previous.clone()))?;
+
+    let mut record = TokenInfo::V2(info);
+    let account_size = record_len(&record)?;
+    check_account_size(config, account_size)?;
+    if account_size > info_account.data_len() {
+        resize_account(info_account, payer, system_program, account_size)?;
+    }
+    // Growing eats into the headroom and shrinking adds to it; the field has
+    // a fixed size, so setting it doesn't move `account_size`.
+    if let TokenInfo::V2(info) = &mut record {
+        info.reserved_bytes = (info_account.data_len() - account_size) as u32;
+    }
+    let new_hash = record_hash(&record)?;
+    write_record(info_account, MAGIC_BYTE, DATA_VERSION, &record)?;
+
+    events::emit(&events::Event::InfoUpdated {
+        mint,
+        changed_fields,
+        old_hash,
+        new_hash,
+    })
+}
+
+/// sha256 of the Borsh body, leaving out the header so rewriting the same
+/// record in a later slot hashes the same.
+fn record_hash(record: &TokenInfo) -> Result<[u8; 32], ProgramError> {
+    Ok(hash(&borsh::to_vec(record)?).to_bytes())
+}
+
+/// Reallocates a program-owned account to `len` bytes, topping up rent from
+/// `payer` if it grew.
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> ProgramResult {
+    if len != account.data_len() {
+        let rent = Rent::get()?;
+        let required = rent.minimum_balance(len);
+        let shortfall = required.saturating_sub(account.lamports());
+        if shortfall > 0 {
+            msg!("[Realloc] Topping up rent by {} lamports", shortfall);
+            invoke(
+                &system_instruction::transfer(payer.key, account.key, shortfall),
+                &[payer.clone(), account.clone(), system_program.clone()],
+            )?;
+        }
+        account.realloc(len, false)?;
+    }
+    Ok(())
+}
+
+/// Encoded length of a record: `AccountHeader`, then the Borsh body.
+fn record_len<T: BorshSerialize>(record: &T) -> Result<usize, ProgramError> {
+    Ok(AccountHeader::LEN + borsh::object_length(record)?)
+}
+
+/// Serializes the header, stamped with the current slot, and the record
+/// straight into the account data, which must be at least `record_len` bytes
+/// long. Bytes past the record are zeroed.
+fn write_record<T: BorshSerialize>(
+    account: &AccountInfo,
+    magic: u8,
+    version: u8,
+    record: &T,
+) -> ProgramResult {
+    let header = AccountHeader {
+        magic,
+        schema_version: version,
+        written_at_slot: clock::Clock::get()?.slot,
+    };
+    let mut data = account.data.borrow_mut();
+    // Each write advances the slice, leaving the tail.
+    let mut rest: &mut [u8] = &mut data;
+    header.serialize(&mut rest)?;
+    record.serialize(&mut rest)?;
+    rest.fill(0);
+    Ok(())
+}
+
+fn process_update_timeline(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    timeline: LaunchTimeline,
+) -> ProgramResult {
+    msg!("[UpdateTimeline] Updating launch timeline");
+
+    let ctx = validation::UpdateInfo::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    let mut info = load_info(program_id, ctx.info)?;
+    validation::key(ctx.creator, &info.creator, "Creator")?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+
+    if let (Some(start), Some(end)) = (timeline.presale_start, timeline.presale_end) {
+        if start > end {
+            msg!(
+                "[Error] Presale start {} is after presale end {}",
+                start,
+                end
+            );
+            return Err(TokenInfoError::InvalidTimeline.into());
+        }
+    }
+
+    msg!("[UpdateTimeline] {:?} -> {:?}", info.timeline, timeline);
+    let previous = info.clone();
+    info.timeline = timeline;
+    info.update_timestamp = clock::Clock::get()?.unix_timestamp;
+
+    store_info(
+        ctx.info,
+        ctx.creator,
+        ctx.system_program,
+        &config,
+        &previous,
+        info,
+    )
+}
+
+fn allow_list(config: &mut Config, list: AllowList) -> &mut Vec<Pubkey> {
+    match list {
+        AllowList::TokenPrograms => &mut config.allowed_token_programs,
+        AllowList::Mints => &mut config.allowed_mints,
+    }
+}
+
+fn store_config_resized<'info>(
+    config_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Config,
+) -> ProgramResult {
+    check_config(config)?;
+    resize_account(config_account, payer, system_program, record_len(config)?)?;
+    write_record(config_account, CONFIG_MAGIC_BYTE, CONFIG_VERSION, config)
+}
+
+fn process_add_to_allow_list(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    list: AllowList,
+    key: Pubkey,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::AddToAllowList { list, key });
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+fn process_remove_from_allow_list(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    list: AllowList,
+    key: Pubkey,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::RemoveFromAllowList { list, key });
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+fn process_add_to_blacklist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::Blacklist::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    validation::uninitialized(ctx.blacklist, "Blacklist entry")?;
+
+    let entry = BlacklistEntry {
+        mint: *ctx.mint.key,
+        added_timestamp: clock::Clock::get()?.unix_timestamp,
+    };
+
+    let account_size = record_len(&entry)?;
+
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(account_size);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.admin.key,
+            ctx.blacklist.key,
+            lamports,
+            account_size as u64,
+            program_id,
+        ),
+        &[
+            ctx.admin.clone(),
+            ctx.blacklist.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[
+            b"blacklist",
+            tenant_seed(&ctx.tenant),
+            ctx.mint.key.as_ref(),
+            &[ctx.blacklist_bump],
+        ]],
+    )?;
+
+    write_record(
+        ctx.blacklist,
+        BLACKLIST_MAGIC_BYTE,
+        BLACKLIST_VERSION,
+        &entry,
+    )?;
+    msg!("[AddToBlacklist] Mint {:?} blacklisted", ctx.mint.key);
+
+    Ok(())
+}
+
+fn process_remove_from_blacklist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::Blacklist::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    validation::owner(ctx.blacklist, program_id, "Blacklist entry")?;
+
+    close_account(ctx.blacklist, ctx.admin)?;
+    msg!("[RemoveFromBlacklist] Mint {:?} removed", ctx.mint.key);
+
+    Ok(())
+}
+
+/// Drains a program-owned account into `destination` and hands it back to
+/// the system program.
+fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? += lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.realloc(0, false)?;
+    account.assign(&system_program::id());
+    Ok(())
+}
+
+fn process_propose_fee_receiver(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    candidate: Pubkey,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+
+    let now = clock::Clock::get()?.unix_timestamp;
+    let executable_at = now.saturating_add(config.fee_receiver_delay);
+    config.pending_fee_receiver = Some(PendingFeeReceiver {
+        candidate,
+        executable_at,
+    });
+    msg!(
+        "[ProposeFeeReceiver] {:?} -> {:?}, executable at {}",
+        config.fee_receiver,
+        candidate,
+        executable_at
+    );
+
+    events::emit(&events::Event::FeeReceiverProposed {
+        current: config.fee_receiver,
+        candidate,
+        executable_at,
+    })?;
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+fn process_accept_fee_receiver(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::AcceptFeeReceiver::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    let Some(pending) = config.pending_fee_receiver else {
+        msg!("[Error] No fee receiver change pending");
+        return Err(TokenInfoError::NoPendingFeeReceiver.into());
+    };
+    validation::key(ctx.candidate, &pending.candidate, "Fee receiver candidate")?;
+
+    let now = clock::Clock::get()?.unix_timestamp;
+    if now < pending.executable_at {
+        msg!(
+            "[Error] Fee receiver change executable at {}, now {}",
+            pending.executable_at,
+            now
+        );
+        return Err(TokenInfoError::TimelockNotElapsed {
+            executable_at: pending.executable_at,
+            now,
+        }
+        .into());
+    }
+
+    let previous = config.fee_receiver;
+    config.fee_receiver = pending.candidate;
+    config.pending_fee_receiver = None;
+    msg!(
+        "[AcceptFeeReceiver] Fee receiver {:?} -> {:?}",
+        previous,
+        config.fee_receiver
+    );
+
+    events::emit(&events::Event::FeeReceiverAccepted {
+        previous,
+        fee_receiver: config.fee_receiver,
+    })?;
+    store_config_resized(ctx.config, ctx.candidate, ctx.system_program, &config)
+}
+
+fn check_no_admin_delay(config: &Config) -> ProgramResult {
+    if config.admin_delay > 0 {
+        msg!(
+            "[Error] Admin delay of {}s is active, queue the change instead",
+            config.admin_delay
+        );
+        return Err(TokenInfoError::TimelockRequired.into());
+    }
+    Ok(())
+}
+
+/// Only mutates: what the result may hold is checked by `Config::violations`
+/// when the config is stored.
+fn apply_admin_action(config: &mut Config, action: &AdminAction) {
+    match *action {
+        AdminAction::SetSponsorshipCap { daily_cap } => {
+            msg!(
+                "[SetSponsorshipCap] Daily cap {} -> {}",
+                config.sponsorship_daily_cap,
+                daily_cap
+            );
+            config.sponsorship_daily_cap = daily_cap;
+        }
+        AdminAction::SetMaxAccountSize { max_account_size } => {
+            msg!(
+                "[SetMaxAccountSize] Max account size {} -> {}",
+                config.max_account_size,
+                max_account_size
+            );
+            config.max_account_size = max_account_size;
+        }
+        AdminAction::SetAllowedDescriptionFormats { ref formats } => {
+            let allowed = formats.iter().fold(0, |mask, format| mask | format.mask());
+            msg!(
+                "[SetAllowedDescriptionFormats] Allowed formats {:#04b} -> {:#04b}",
+                config.allowed_description_formats,
+                allowed
+            );
+            config.allowed_description_formats = allowed;
+        }
+        AdminAction::AddToAllowList { list, key } => {
+            let entries = allow_list(config, list);
+            if entries.contains(&key) {
+                msg!("[AddToAllowList] {:?} already in {:?}", key, list);
+            } else {
+                entries.push(key);
+                msg!("[AddToAllowList] Added {:?} to {:?}", key, list);
+            }
+        }
+        AdminAction::RemoveFromAllowList { list, key } => {
+            let entries = allow_list(config, list);
+            let before = entries.len();
+            entries.retain(|entry| *entry != key);
+            if entries.len() == before {
+                msg!("[RemoveFromAllowList] {:?} not in {:?}", key, list);
+            } else {
+                msg!("[RemoveFromAllowList] Removed {:?} from {:?}", key, list);
+            }
+        }
+        AdminAction::SetFeeReceiverDelay { delay } => {
+            msg!(
+                "[SetFeeReceiverDelay] Fee receiver delay {}s -> {}s",
+                config.fee_receiver_delay,
+                delay
+            );
+            config.fee_receiver_delay = delay;
+        }
+        AdminAction::SetAdmin { admin } => {
+            msg!("[SetAdmin] Admin {:?} -> {:?}", config.admin, admin);
+            config.admin = admin;
+        }
+        AdminAction::SetLoyaltyTiers { ref tiers } => {
+            msg!(
+                "[SetLoyaltyTiers] {} tiers -> {} tiers",
+                config.loyalty_tiers.len(),
+                tiers.len()
+            );
+            config.loyalty_tiers = tiers.clone();
+        }
+        AdminAction::SetUsdFee { usd_fee } => {
+            if let Some(usd_fee) = usd_fee {
+                msg!(
+                    "[SetUsdFee] {} micro-USD, {}..={} lamports",
+                    usd_fee.micro_usd,
+                    usd_fee.min_lamports,
+                    usd_fee.max_lamports
+                );
+            } else {
+                msg!("[SetUsdFee] Back to fixed fee of {} lamports", FEE_AMOUNT);
+            }
+            config.usd_fee = usd_fee;
+        }
+        AdminAction::SetCreatorDailyQuota { quota } => {
+            msg!(
+                "[SetCreatorDailyQuota] Daily quota {} -> {}",
+                config.creator_daily_quota,
+                quota
+            );
+            config.creator_daily_quota = quota;
+        }
+        AdminAction::SetLinkAttester { attester } => {
+            msg!(
+                "[SetLinkAttester] Link attester {:?} -> {:?}",
+                config.link_attester,
+                attester
+            );
+            config.link_attester = attester;
+        }
+        AdminAction::SetVerificationDispute { delay, bond } => {
+            msg!(
+                "[SetVerificationDispute] Dispute delay {}s -> {}s, bond {} -> {}",
+                config.verification_dispute_delay,
+                delay,
+                config.challenge_bond,
+                bond
+            );
+            config.verification_dispute_delay = delay;
+            config.challenge_bond = bond;
+        }
+        AdminAction::SetListingBondLock { lock } => {
+            msg!(
+                "[SetListingBondLock] Listing bond lock {}s -> {}s",
+                config.listing_bond_lock,
+                lock
+            );
+            config.listing_bond_lock = lock;
+        }
+        AdminAction::SetV1CutoverSlot { slot } => {
+            msg!(
+                "[SetV1CutoverSlot] V1 cutover slot {:?} -> {:?}",
+                config.v1_cutover_slot,
+                slot
+            );
+            config.v1_cutover_slot = slot;
+        }
+        AdminAction::SetAdminDelay { delay } => {
+            msg!(
+                "[SetAdminDelay] Admin delay {}s -> {}s",
+                config.admin_delay,
+                delay
+            );
+            config.admin_delay = delay;
+        }
+        AdminAction::SetAuthority { authority } => {
+            msg!(
+                "[SetAuthority] Authority {:?} -> {:?}",
+                config.authority,
+                authority
+            );
+            config.authority = authority;
+        }
+    }
+}
+
+/// Run by every config write, so a config can't be stored with a violation.
+fn check_config(config: &Config) -> ProgramResult {
+    let violations = config.violations();
+    if violations.is_empty() {
+        return Ok(());
+    }
+    for violation in &violations {
+        msg!("[Error] Config violation: {:?}", violation);
+    }
+    Err(TokenInfoError::InvalidConfig {
+        violations: violations.len() as u32,
+    }
+    .into())
+}
+
+fn process_validate_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    action: Option<AdminAction>,
+) -> ProgramResult {
+    let ctx = validation::ValidateConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    if let Some(action) = &action {
+        apply_admin_action(&mut config, action);
+    }
+    let violations = config.violations();
+    msg!("[ValidateConfig] {} violations", violations.len());
+    set_return_data(&borsh::to_vec(&violations)?);
+    Ok(())
+}
+
+fn process_get_stats(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::GetStats::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    let stats = Stats {
+        tenant: config.tenant,
+        total_creates: config.total_creates,
+        sponsored_creates: config.sponsored_creates,
+        sponsorship_day: config.sponsorship_day,
+        sponsorship_spent_today: config.sponsorship_spent_today,
+        sponsorship_vault_balance: ctx.vault.lamports(),
+        next_action_id: config.next_action_id,
+    };
+    msg!("[GetStats] {:?}", stats);
+    set_return_data(&borsh::to_vec(&stats)?);
+    Ok(())
+}
+
+fn process_get_mint_stats(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::GetMintStats::load(program_id, accounts)?;
+
+    if ctx.info.data_is_empty() {
+        let stats = MintStats {
+            mint: Pubkey::default(),
+            creates: 0,
+            creation_slot: 0,
+            update_timestamp: 0,
+            endorsements: 0,
+            verified: false,
+            challenged: false,
+            listing_bond: 0,
+        };
+        msg!("[GetMintStats] {:?}", stats);
+        set_return_data(&borsh::to_vec(&stats)?);
+        return Ok(());
+    }
+    let info = load_info(program_id, ctx.info)?;
+    let listing_bond = if ctx.listing_bond.data_is_empty() {
+        0
+    } else {
+        load_listing_bond(program_id, ctx.listing_bond)?.amount
+    };
+    let stats = MintStats {
+        mint: info.mint,
+        creates: 1,
+        creation_slot: info.creation_slot,
+        update_timestamp: info.update_timestamp,
+        endorsements: info.endorsements,
+        verified: info.is_verified(clock::Clock::get()?.unix_timestamp),
+        challenged: info.challenged,
+        listing_bond,
+    };
+    msg!("[GetMintStats] {:?}", stats);
+    set_return_data(&borsh::to_vec(&stats)?);
+    Ok(())
+}
+
+fn load_pending_action(
+    program_id: &Pubkey,
+    pending_action: &AccountInfo,
+) -> Result<PendingAction, ProgramError> {
+    validation::state(
+        pending_action,
+        program_id,
+        PENDING_ACTION_MAGIC_BYTE,
+        Some(PENDING_ACTION_VERSION),
+        "Pending action account",
+    )?;
+    let data = pending_action.data.borrow();
+    PendingAction::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+fn process_queue_admin_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    action: AdminAction,
+) -> ProgramResult {
+    let ctx = validation::QueueAdminAction::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+
+    let id = config.next_action_id;
+    let bump = validation::pda(
+        ctx.pending_action,
+        find_pending_action_account(&config.tenant, id, program_id),
+        "Pending action account",
+    )?;
+    validation::uninitialized(ctx.pending_action, "Pending action account")?;
+
+    let now = clock::Clock::get()?.unix_timestamp;
+    let pending = PendingAction {
+        id,
+        action,
+        queued_by: *ctx.admin.key,
+        queued_at: now,
+        executable_at: now.saturating_add(config.admin_delay),
+    };
+
+    let account_size = record_len(&pending)?;
+
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(account_size);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.admin.key,
+            ctx.pending_action.key,
+            lamports,
+            account_size as u64,
+            program_id,
+        ),
+        &[
+            ctx.admin.clone(),
+            ctx.pending_action.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[
+            b"pending_action",
+            tenant_seed(&config.tenant),
+            &id.to_le_bytes(),
+            &[bump],
+        ]],
+    )?;
+
+    write_record(
+        ctx.pending_action,
+        PENDING_ACTION_MAGIC_BYTE,
+        PENDING_ACTION_VERSION,
+        &pending,
+    )?;
+
+    config.next_action_id = id + 1;
+    store_config(ctx.config, &config)?;
+    msg!(
+        "[QueueAdminAction] Action {} queued, executable at {}",
+        id,
+        pending.executable_at
+    );
+
+    events::emit(&events::Event::AdminActionQueued {
+        id,
+        action: pending.action,
+        executable_at: pending.executable_at,
+    })
+}
+
+fn process_execute_admin_action(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::ExecuteAdminAction::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    let pending = load_pending_action(program_id, ctx.pending_action)?;
+    validation::pda(
+        ctx.pending_action,
+        find_pending_action_account(&config.tenant, pending.id, program_id),
+        "Pending action account",
+    )?;
+    validation::key(ctx.queued_by, &pending.queued_by, "Queued by")?;
+
+    let now = clock::Clock::get()?.unix_timestamp;
+    if now < pending.executable_at {
+        msg!(
+            "[Error] Action {} executable at {}, now {}",
+            pending.id,
+            pending.executable_at,
+            now
+        );
+        return Err(TokenInfoError::TimelockNotElapsed {
+            executable_at: pending.executable_at,
+            now,
+        }
+        .into());
+    }
+
+    apply_admin_action(&mut config, &pending.action);
+    store_config_resized(ctx.config, ctx.executor, ctx.system_program, &config)?;
+
+    close_account(ctx.pending_action, ctx.queued_by)?;
+    msg!("[ExecuteAdminAction] Action {} executed", pending.id);
+
+    events::emit(&events::Event::AdminActionExecuted { id: pending.id })
+}
+
+fn process_cancel_admin_action(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::CancelAdminAction::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+
+    let pending = load_pending_action(program_id, ctx.pending_action)?;
+    validation::pda(
+        ctx.pending_action,
+        find_pending_action_account(&config.tenant, pending.id, program_id),
+        "Pending action account",
+    )?;
+    validation::key(ctx.queued_by, &pending.queued_by, "Queued by")?;
+
+    close_account(ctx.pending_action, ctx.queued_by)?;
+    msg!("[CancelAdminAction] Action {} cancelled", pending.id);
+
+    events::emit(&events::Event::AdminActionCancelled { id: pending.id })
+}
+
+fn process_set_loyalty_tiers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    tiers: Vec<LoyaltyTier>,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetLoyaltyTiers { tiers });
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+fn process_set_usd_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    usd_fee: Option<UsdFee>,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetUsdFee { usd_fee });
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+/// Hash of every field `CreateInfoArgs` sets, leaving out the payer,
+/// timestamps and mint snapshots that differ between otherwise identical
+/// creates.
+pub fn content_hash(info: &TokenInfoV2) -> Result<Hash, ProgramError> {
+    Ok(hashv(&[
+        info.mint.as_ref(),
+        info.creator.as_ref(),
+        &info.reserved_bytes.to_le_bytes(),
+        &info.symbol,
+        &info.name,
+        &borsh::to_vec(&info.origin)?,
+        &borsh::to_vec(&info.description_format)?,
+        &borsh::to_vec(&info.description)?,
+        &borsh::to_vec(&info.links)?,
+        &borsh::to_vec(&info.images)?,
+        &borsh::to_vec(&info.image_metadata)?,
+    ]))
+}
+
+fn process_create_info_idempotent(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateInfoArgs,
+) -> ProgramResult {
+    let ctx = validation::CreateInfo::load_allow_existing(program_id, accounts, false)?;
+    if ctx.info.data_is_empty() {
+        return process_create_info(program_id, accounts, args, CreateMode::Pay);
+    }
+    let config = load_config(program_id, ctx.config)?;
+    validation::create_authority(ctx.authority, &config)?;
+
+    let existing = load_info(program_id, ctx.info)?;
+    let existing_hash = content_hash(&existing)?;
+    let requested_hash =
+        content_hash(&build_info(ctx.mint, ctx.creator.key, ctx.payer.key, args)?)?;
+
+    if existing_hash != requested_hash {
+        msg!(
+            "[Error] Info account exists with different content: {} != {}",
+            existing_hash,
+            requested_hash
+        );
+        return Err(TokenInfoError::AccountAlreadyExists.into());
+    }
+
+    msg!(
+        "[CreateInfoIdempotent] Record already exists with content {}, nothing to do",
+        existing_hash
+    );
+    set_return_data(&borsh::to_vec(&CreatedInfo {
+        info_account: *ctx.info.key,
+        bump: ctx.info_bump,
+    })?);
+    Ok(())
+}
+
+fn process_set_verified_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    flags: Vec<bool>,
+) -> ProgramResult {
+    let ctx = validation::AdminBatch::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    let now = clock::Clock::get()?.unix_timestamp;
+
+    if flags.len() != ctx.remaining.len() {
+        msg!(
+            "[Error] {} flags for {} info accounts",
+            flags.len(),
+            ctx.remaining.len()
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    for (info_account, verified) in ctx.remaining.iter().zip(flags) {
+        let mut info = load_info(program_id, info_account)?;
+        validation::pda(
+            info_account,
+            find_info_account(&config.tenant, &info.mint, program_id),
+            "Info account",
+        )?;
+
+        if info.verified == verified {
+            continue;
+        }
+        // Open challenges are settled by ResolveChallenge, which also pays out the bond.
+        if info.challenged {
+            msg!(
+                "[SetVerifiedBatch] Skipping {:?}: challenge pending",
+                info.mint
+            );
+            continue;
+        }
+        let previous = info.clone();
+        info.verified = verified;
+        info.verified_at = if verified {
+            now.saturating_add(config.verification_dispute_delay)
+        } else {
+            0
+        };
+        msg!(
+            "[SetVerifiedBatch] {:?} verified: {} from {}",
+            info.mint,
+            verified,
+            info.verified_at
+        );
+        store_info(
+            info_account,
+            ctx.admin,
+            ctx.system_program,
+            &config,
+            &previous,
+            info,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn process_set_creator_daily_quota(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    quota: u32,
+) -> ProgramResult {
+    let ctx = validation::AdminConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetCreatorDailyQuota { quota });
+    store_config(ctx.config, &config)
+}
+
+fn process_set_link_attester(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    attester: Option<Pubkey>,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetLinkAttester { attester });
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+fn process_set_v1_cutover_slot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slot: Option<u64>,
+) -> ProgramResult {
+    let ctx = validation::AdminConfigRealloc::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetV1CutoverSlot { slot });
+    store_config_resized(ctx.config, ctx.admin, ctx.system_program, &config)
+}
+
+fn check_v1_cutover(config: &Config) -> ProgramResult {
+    let Some(cutover_slot) = config.v1_cutover_slot else {
+        return Ok(());
+    };
+    if clock::Clock::get()?.slot >= cutover_slot {
+        msg!(
+            "[Error] Legacy CreateInfo is disabled since slot {}; use CreateInfoV2",
+            cutover_slot
+        );
+        return Err(TokenInfoError::DeprecatedInstruction { cutover_slot }.into());
+    }
+    Ok(())
+}
+
+fn load_link_attestations(
+    program_id: &Pubkey,
+    account: &AccountInfo,
+) -> Result<LinkAttestations, ProgramError> {
+    validation::state(
+        account,
+        program_id,
+        LINK_ATTESTATIONS_MAGIC_BYTE,
+        Some(LINK_ATTESTATIONS_VERSION),
+        "Link attestations account",
+    )?;
+    let data = account.data.borrow();
+    LinkAttestations::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+fn process_attest_link(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    index: u32,
+    status: LinkStatus,
+) -> ProgramResult {
+    let ctx = validation::AttestLink::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    if *ctx.attester.key != config.admin && Some(*ctx.attester.key) != config.link_attester {
+        msg!("[Error] {:?} may not attest links", ctx.attester.key);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let info = load_info(program_id, ctx.info)?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+    let Some(link) = info.links.get(index as usize) else {
+        msg!(
+            "[Error] Link index {} out of range, record has {} links",
+            index,
+            info.links.len()
+        );
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    let mut attestations = if ctx.attestations.data_is_empty() {
+        LinkAttestations {
+            mint: info.mint,
+            links: Vec::new(),
+        }
+    } else {
+        load_link_attestations(program_id, ctx.attestations)?
+    };
+    if attestations.links.len() <= index as usize {
+        attestations.links.resize(index as usize + 1, None);
+    }
+    let checked_at = clock::Clock::get()?.unix_timestamp;
+    attestations.links[index as usize] = Some(LinkAttestation {
+        url_hash: hash(link.url.as_bytes()).to_bytes(),
+        status,
+        checked_at,
+    });
+    msg!(
+        "[AttestLink] {:?} link {} is {:?}",
+        info.mint,
+        index,
+        status
+    );
+
+    let account_size = record_len(&attestations)?;
+
+    if ctx.attestations.data_is_empty() {
+        let lamports = Rent::get()?.minimum_balance(account_size);
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.attester.key,
+                ctx.attestations.key,
+                lamports,
+                account_size as u64,
+                program_id,
+            ),
+            &[
+                ctx.attester.clone(),
+                ctx.attestations.clone(),
+                ctx.system_program.clone(),
+            ],
+            &[&[
+                b"link_attestations",
+                ctx.info.key.as_ref(),
+                &[ctx.attestations_bump],
+            ]],
+        )?;
+    }
+    resize_account(
+        ctx.attestations,
+        ctx.attester,
+        ctx.system_program,
+        account_size,
+    )?;
+    write_record(
+        ctx.attestations,
+        LINK_ATTESTATIONS_MAGIC_BYTE,
+        LINK_ATTESTATIONS_VERSION,
+        &attestations,
+    )?;
+
+    events::emit(&events::Event::LinkAttested {
+        mint: info.mint,
+        index,
+        status,
+        checked_at,
+    })
+}
+
+fn process_update_images(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    icon_uri: String,
+    header_uri: String,
+    image_metadata: ImagesMetadata,
+) -> ProgramResult {
+    msg!("[UpdateImages] Updating images");
+
+    let ctx = validation::UpdateInfo::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    let mut info = load_info(program_id, ctx.info)?;
+    validation::key(ctx.creator, &info.creator, "Creator")?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+
+    check_ipfs_uri(&icon_uri)?;
+    check_ipfs_uri(&header_uri)?;
+    check_image_metadata(&image_metadata)?;
+
+    let previous = info.clone();
+    info.images = Images {
+        icon: icon_uri,
+        header: header_uri,
+    };
+    info.image_metadata = image_metadata;
+    info.update_timestamp = clock::Clock::get()?.unix_timestamp;
+
+    store_info(
+        ctx.info,
+        ctx.creator,
+        ctx.system_program,
+        &config,
+        &previous,
+        info,
+    )
+}
+
+fn process_trim_info_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("[TrimInfoAccount] Trimming info account");
+
+    let ctx = validation::UpdateInfo::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    let mut info = load_info(program_id, ctx.info)?;
+    validation::key(ctx.creator, &info.creator, "Creator")?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+
+    let mint = info.mint;
+    let old_hash = record_hash(&TokenInfo::V2(info.clone()))?;
+    info.reserved_bytes = 0;
+    let record = TokenInfo::V2(info);
+    let new_hash = record_hash(&record)?;
+    let account_size = record_len(&record)?;
+    if account_size < ctx.info.data_len() {
+        msg!(
+            "[TrimInfoAccount] Shrinking from {} to {} bytes",
+            ctx.info.data_len(),
+            account_size
+        );
+        ctx.info.realloc(account_size, false)?;
+    }
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &record)?;
+
+    let excess = ctx
+        .info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(account_size));
+    if excess > 0 {
+        **ctx.info.try_borrow_mut_lamports()? -= excess;
+        **ctx.creator.try_borrow_mut_lamports()? += excess;
+    }
+    msg!("[TrimInfoAccount] Refunded {} lamports", excess);
+
+    events::emit(&events::Event::InfoUpdated {
+        mint,
+        changed_fields: 0,
+        old_hash,
+        new_hash,
+    })
+}
+
+fn process_refund_receipt(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("[RefundReceipt] Refunding receipt");
+
+    let ctx = validation::RefundReceipt::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    validation::fee_receiver(ctx.fee_receiver, &config)?;
+
+    let receipt = load_receipt(program_id, ctx.receipt)?;
+    let amount = receipt.fee;
+    refund_receipt(
+        program_id,
+        &config,
+        ctx.receipt,
+        receipt,
+        ctx.payer,
+        ctx.fee_receiver,
+        None,
+        ctx.system_program,
+        amount,
+    )
+}
+
+/// Counts `cost` against today's sponsorship cap, rolling the day over first.
+fn charge_sponsorship(config: &mut Config, cost: u64) -> ProgramResult {
+    let today = clock::Clock::get()?.unix_timestamp / SECONDS_PER_DAY;
+    if config.sponsorship_day != today {
+        config.sponsorship_day = today;
+        config.sponsorship_spent_today = 0;
+    }
+
+    let spent = config.sponsorship_spent_today;
+    if spent.saturating_add(cost) > config.sponsorship_daily_cap {
+        msg!(
+            "[Error] Sponsorship cap exceeded: spent {}, requested {}, cap {}",
+            spent,
+            cost,
+            config.sponsorship_daily_cap
+        );
+        return Err(TokenInfoError::SponsorshipCapExceeded {
+            cap: config.sponsorship_daily_cap,
+            spent,
+            requested: cost,
+        }
+        .into());
+    }
+    config.sponsorship_spent_today = spent + cost;
+    Ok(())
+}
+
+fn process_refund_fee(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    msg!("[RefundFee] Refunding {} lamports", amount);
+
+    let ctx = validation::RefundFee::load(program_id, accounts)?;
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+
+    // Refunds come out of the same vault as sponsored creates, so they share its cap.
+    charge_sponsorship(&mut config, amount)?;
+    store_config(ctx.config, &config)?;
+
+    let receipt = load_receipt(program_id, ctx.receipt)?;
+    refund_receipt(
+        program_id,
+        &config,
+        ctx.receipt,
+        receipt,
+        ctx.payer,
+        ctx.vault,
+        Some(&[
+            b"sponsorship_vault",
+            tenant_seed(&config.tenant),
+            &[ctx.vault_bump],
+        ]),
+        ctx.system_program,
+        amount,
+    )
+}
+
+/// Pays `amount`, at most the receipt's fee, back to its payer out of
+/// `source`, which signs with `source_seeds` when it is a PDA, and marks the
+/// receipt refunded. A receipt of this tenant is refunded at most once.
+#[allow(clippy::too_many_arguments)]
+fn refund_receipt<'info>(
+    program_id: &Pubkey,
+    config: &Config,
+    receipt_account: &AccountInfo<'info>,
+    mut receipt: Receipt,
+    payer: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    source_seeds: Option<&[&[u8]]>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    validation::pda(
+        receipt_account,
+        find_receipt_account(&config.tenant, &receipt.payer, &receipt.mint, program_id),
+        "Receipt",
+    )?;
+    validation::key(payer, &receipt.payer, "Payer")?;
+    if receipt.refunded > 0 {
+        msg!(
+            "[Error] Receipt for {:?} already refunded {} lamports",
+            receipt.mint,
+            receipt.refunded
+        );
+        return Err(TokenInfoError::AlreadyRefunded.into());
+    }
+    // `refunded` doubles as the refunded flag, so it can't be left at zero.
+    if amount == 0 {
+        msg!("[Error] Nothing to refund for {:?}", receipt.mint);
+        return Err(ProgramError::InvalidArgument);
+    }
+    if amount > receipt.fee {
+        msg!(
+            "[Error] Refund of {} exceeds the {} lamport fee",
+            amount,
+            receipt.fee
+        );
+        return Err(TokenInfoError::RefundExceedsFee {
+            amount,
+            fee: receipt.fee,
+        }
+        .into());
+    }
+
+    invoke_signed(
+        &system_instruction::transfer(source.key, payer.key, amount),
+        &[source.clone(), payer.clone(), system_program.clone()],
+        source_seeds.as_slice(),
+    )?;
+    receipt.refunded = amount;
+    write_record(
+        receipt_account,
+        RECEIPT_MAGIC_BYTE,
+        RECEIPT_VERSION,
+        &receipt,
+    )?;
+    msg!(
+        "[Refund] Returned {} lamports to {:?} for {:?}",
+        amount,
+        receipt.payer,
+        receipt.mint
+    );
+
+    events::emit(&events::Event::FeeRefunded {
+        payer: receipt.payer,
+        mint: receipt.mint,
+        amount,
+    })
+}
+
+fn process_emit_snapshot(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::EmitSnapshot::load(program_id, accounts)?;
+
+    validation::state(ctx.info, program_id, MAGIC_BYTE, None, "Info account")?;
+    let info = {
+        // V1 records too.
+        TokenInfo::from_account_data(&ctx.info.data.borrow())
+            .ok_or(ProgramError::InvalidAccountData)?
+    };
+    let slot = clock::Clock::get()?.slot;
+    msg!(
+        "[EmitSnapshot] Snapshot of {:?} at slot {}",
+        ctx.info.key,
+        slot
+    );
+
+    events::emit(&events::Event::InfoSnapshot {
+        info_account: *ctx.info.key,
+        slot,
+        info,
+    })
+}
+
+fn process_endorse(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::Endorse::load(program_id, accounts)?;
+    let mut info = load_info(program_id, ctx.info)?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.endorser.key,
+            ctx.endorsement.key,
+            Rent::get()?.minimum_balance(ENDORSEMENT_LEN),
+            ENDORSEMENT_LEN as u64,
+            program_id,
+        ),
+        &[
+            ctx.endorser.clone(),
+            ctx.endorsement.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[
+            b"endorsement",
+            ctx.info.key.as_ref(),
+            ctx.endorser.key.as_ref(),
+            &[ctx.endorsement_bump],
+        ]],
+    )?;
+    write_record(
+        ctx.endorsement,
+        ENDORSEMENT_MAGIC_BYTE,
+        ENDORSEMENT_VERSION,
+        &(),
+    )?;
+
+    // Fixed-size field, so the record keeps its length.
+    info.endorsements = info.endorsements.saturating_add(1);
+    let (mint, endorsements) = (info.mint, info.endorsements);
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &TokenInfo::V2(info))?;
+    msg!(
+        "[Endorse] {:?} endorsed {:?} ({} endorsements)",
+        ctx.endorser.key,
+        mint,
+        endorsements
+    );
+
+    events::emit(&events::Event::Endorsed {
+        mint,
+        endorser: *ctx.endorser.key,
+        endorsements,
+    })
+}
+
+fn process_set_verification_dispute(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delay: i64,
+    bond: u64,
+) -> ProgramResult {
+    let ctx = validation::AdminConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(
+        &mut config,
+        &AdminAction::SetVerificationDispute { delay, bond },
+    );
+    store_config(ctx.config, &config)
+}
+
+fn process_challenge_verification(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::ChallengeVerification::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    let mut info = load_info(program_id, ctx.info)?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+
+    let now = clock::Clock::get()?.unix_timestamp;
+    if !info.verified || info.challenged || now >= info.verified_at {
+        msg!(
+            "[Error] {:?} has no verification in its dispute window",
+            info.mint
+        );
+        return Err(TokenInfoError::NotChallengeable.into());
+    }
+
+    let bond = config.challenge_bond;
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.challenger.key,
+            ctx.challenge.key,
+            Rent::get()?
+                .minimum_balance(VerificationChallenge::LEN)
+                .saturating_add(bond),
+            VerificationChallenge::LEN as u64,
+            program_id,
+        ),
+        &[
+            ctx.challenger.clone(),
+            ctx.challenge.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[
+            b"verification_challenge",
+            ctx.info.key.as_ref(),
+            &[ctx.challenge_bump],
+        ]],
+    )?;
+    let challenge = VerificationChallenge {
+        challenger: *ctx.challenger.key,
+        mint: info.mint,
+        bond,
+        challenged_at: now,
+    };
+    write_record(
+        ctx.challenge,
+        CHALLENGE_MAGIC_BYTE,
+        CHALLENGE_VERSION,
+        &challenge,
+    )?;
+
+    info.challenged = true;
+    let mint = info.mint;
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &TokenInfo::V2(info))?;
+    msg!(
+        "[ChallengeVerification] {:?} challenged by {:?} with a {} lamport bond",
+        mint,
+        ctx.challenger.key,
+        bond
+    );
+
+    events::emit(&events::Event::VerificationChallenged {
+        mint,
+        challenger: *ctx.challenger.key,
+        bond,
+    })
+}
+
+fn process_resolve_challenge(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    upheld: bool,
+) -> ProgramResult {
+    let ctx = validation::ResolveChallenge::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    validation::fee_receiver(ctx.fee_receiver, &config)?;
+    let mut info = load_info(program_id, ctx.info)?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+    let challenge = load_challenge(program_id, ctx.challenge)?;
+    validation::key(ctx.challenger, &challenge.challenger, "Challenger")?;
+
+    let bond_to = if upheld {
+        info.verified = false;
+        info.verified_at = 0;
+        ctx.challenger
+    } else {
+        ctx.fee_receiver
+    };
+    info.challenged = false;
+    let mint = info.mint;
+    write_record(ctx.info, MAGIC_BYTE, DATA_VERSION, &TokenInfo::V2(info))?;
+
+    **ctx.challenge.try_borrow_mut_lamports()? -= challenge.bond;
+    **bond_to.try_borrow_mut_lamports()? += challenge.bond;
+    close_account(ctx.challenge, ctx.challenger)?;
+    msg!(
+        "[ResolveChallenge] Challenge on {:?} {}, bond of {} to {:?}",
+        mint,
+        if upheld { "upheld" } else { "rejected" },
+        challenge.bond,
+        bond_to.key
+    );
+
+    events::emit(&events::Event::ChallengeResolved { mint, upheld })
+}
+
+fn load_challenge(
+    program_id: &Pubkey,
+    challenge: &AccountInfo,
+) -> Result<VerificationChallenge, ProgramError> {
+    validation::state(
+        challenge,
+        program_id,
+        CHALLENGE_MAGIC_BYTE,
+        Some(CHALLENGE_VERSION),
+        "Challenge account",
+    )?;
+    let data = challenge.data.borrow();
+    VerificationChallenge::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+fn process_set_listing_bond_lock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock: i64,
+) -> ProgramResult {
+    let ctx = validation::AdminConfig::load(program_id, accounts)?;
+
+    let mut config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    check_no_admin_delay(&config)?;
+
+    apply_admin_action(&mut config, &AdminAction::SetListingBondLock { lock });
+    store_config(ctx.config, &config)
+}
+
+fn process_post_listing_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let ctx = validation::PostListingBond::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    let info = load_info(program_id, ctx.info)?;
+    validation::key(ctx.creator, &info.creator, "Creator")?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &info.mint, program_id),
+        "Info account",
+    )?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.creator.key,
+            ctx.bond.key,
+            Rent::get()?
+                .minimum_balance(ListingBond::LEN)
+                .saturating_add(amount),
+            ListingBond::LEN as u64,
+            program_id,
+        ),
+        &[
+            ctx.creator.clone(),
+            ctx.bond.clone(),
+            ctx.system_program.clone(),
+        ],
+        &[&[b"listing_bond", ctx.info.key.as_ref(), &[ctx.bond_bump]]],
+    )?;
+    let bond = ListingBond {
+        creator: info.creator,
+        mint: info.mint,
+        amount,
+        posted_at: clock::Clock::get()?.unix_timestamp,
+    };
+    write_record(
+        ctx.bond,
+        LISTING_BOND_MAGIC_BYTE,
+        LISTING_BOND_VERSION,
+        &bond,
+    )?;
+    msg!(
+        "[PostListingBond] {} lamports bonded for {:?}",
+        amount,
+        info.mint
+    );
+
+    events::emit(&events::Event::ListingBondPosted {
+        mint: info.mint,
+        amount,
+    })
+}
+
+fn process_reclaim_bond(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::ReclaimBond::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    let bond = load_listing_bond(program_id, ctx.bond)?;
+    validation::key(ctx.creator, &bond.creator, "Creator")?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &bond.mint, program_id),
+        "Info account",
+    )?;
+    // The bond backs the listing until the admin has ruled on it.
+    if load_info(program_id, ctx.info)?.challenged {
+        msg!("[Error] Challenge pending on {:?}", bond.mint);
+        return Err(TokenInfoError::ChallengePending.into());
+    }
+
+    let unlocks_at = bond.posted_at.saturating_add(config.listing_bond_lock);
+    if clock::Clock::get()?.unix_timestamp < unlocks_at {
+        msg!("[Error] Listing bond is locked until {}", unlocks_at);
+        return Err(TokenInfoError::BondLocked { unlocks_at }.into());
+    }
+
+    close_account(ctx.bond, ctx.creator)?;
+    msg!(
+        "[ReclaimBond] {} lamports returned for {:?}",
+        bond.amount,
+        bond.mint
+    );
+
+    events::emit(&events::Event::ListingBondReleased {
+        mint: bond.mint,
+        amount: bond.amount,
+        forfeited: false,
+    })
+}
+
+fn process_forfeit_bond(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::ForfeitBond::load(program_id, accounts)?;
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    validation::fee_receiver(ctx.fee_receiver, &config)?;
+    let bond = load_listing_bond(program_id, ctx.bond)?;
+    validation::key(ctx.creator, &bond.creator, "Creator")?;
+    validation::pda(
+        ctx.info,
+        find_info_account(&config.tenant, &bond.mint, program_id),
+        "Info account",
+    )?;
+
+    **ctx.bond.try_borrow_mut_lamports()? -= bond.amount;
+    **ctx.fee_receiver.try_borrow_mut_lamports()? += bond.amount;
+    close_account(ctx.bond, ctx.creator)?;
+    msg!(
+        "[ForfeitBond] {} lamports of {:?} forfeited",
+        bond.amount,
+        bond.mint
+    );
+
+    events::emit(&events::Event::ListingBondReleased {
+        mint: bond.mint,
+        amount: bond.amount,
+        forfeited: true,
+    })
+}
+
+fn process_set_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    payout: Pubkey,
+    share_bps: u16,
+) -> ProgramResult {
+    let ctx = validation::OperatorEntry::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    if share_bps as u64 > BPS_DENOMINATOR {
+        msg!("[Error] Operator share {} bps exceeds 100%", share_bps);
+        return Err(TokenInfoError::InvalidOperatorShare { share_bps }.into());
+    }
+
+    if ctx.operator_account.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.admin.key,
+                ctx.operator_account.key,
+                Rent::get()?.minimum_balance(Operator::LEN),
+                Operator::LEN as u64,
+                program_id,
+            ),
+            &[
+                ctx.admin.clone(),
+                ctx.operator_account.clone(),
+                ctx.system_program.clone(),
+            ],
+            &[&[
+                b"operator",
+                tenant_seed(&ctx.tenant),
+                ctx.operator.key.as_ref(),
+                &[ctx.operator_bump],
+            ]],
+        )?;
+    } else {
+        load_operator(program_id, ctx.operator_account)?;
+    }
+
+    let operator = Operator {
+        operator: *ctx.operator.key,
+        payout,
+        share_bps,
+    };
+    write_record(
+        ctx.operator_account,
+        OPERATOR_MAGIC_BYTE,
+        OPERATOR_VERSION,
+        &operator,
+    )?;
+    msg!(
+        "[SetOperator] {:?} pays {} bps to {:?}",
+        ctx.operator.key,
+        share_bps,
+        payout
+    );
+    Ok(())
+}
+
+fn process_remove_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let ctx = validation::OperatorEntry::load(program_id, accounts)?;
+
+    let config = load_config(program_id, ctx.config)?;
+    validation::key(ctx.admin, &config.admin, "Admin")?;
+    load_operator(program_id, ctx.operator_account)?;
+
+    close_account(ctx.operator_account, ctx.admin)?;
+    msg!("[RemoveOperator] Operator {:?} removed", ctx.operator.key);
+    Ok(())
+}
+
+fn load_operator(program_id: &Pubkey, operator: &AccountInfo) -> Result<Operator, ProgramError> {
+    validation::state(
+        operator,
+        program_id,
+        OPERATOR_MAGIC_BYTE,
+        Some(OPERATOR_VERSION),
+        "Operator account",
+    )?;
+    let data = operator.data.borrow();
+    Operator::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+fn load_listing_bond(program_id: &Pubkey, bond: &AccountInfo) -> Result<ListingBond, ProgramError> {
+    validation::state(
+        bond,
+        program_id,
+        LISTING_BOND_MAGIC_BYTE,
+        Some(LISTING_BOND_VERSION),
+        "Listing bond account",
+    )?;
+    let data = bond.data.borrow();
+    ListingBond::try_from_slice(&data[AccountHeader::LEN..])
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v1_record() -> TokenInfoV1 {
+        TokenInfoV1 {
+            mint: "So11111111111111111111111111111111111111112".into(),
+            description: "Legacy token".into(),
+            links: Vec::new(),
+            images: Images {
+                icon: "https://example.com/icon.png".into(),
+                header: String::new(),
+            },
+            creation_timestamp: 1_650_000_000,
+            update_timestamp: 1_650_000_000,
+        }
+    }
+
+    fn valid_config() -> Config {
+        new_config(
+            Pubkey::new_unique(),
+            FEE_AMOUNT,
+            Pubkey::new_unique(),
+            GLOBAL_TENANT,
+            None,
+        )
+    }
+
+    #[test]
+    fn new_config_has_no_violations() {
+        assert_eq!(valid_config().violations(), Vec::new());
+    }
+
+    #[test]
+    fn violations_reports_every_rule_broken() {
+        let mut config = valid_config();
+        config.admin = Pubkey::default();
+        config.admin_delay = -1;
+        config.loyalty_tiers = vec![
+            LoyaltyTier {
+                min_paid_creations: 5,
+                discount_bps: 10_001,
+            },
+            LoyaltyTier {
+                min_paid_creations: 5,
+                discount_bps: 0,
+            },
+        ];
+        config.usd_fee = Some(UsdFee {
+            feed_id: oracle::SOL_USD_FEED_ID,
+            micro_usd: 1_000_000,
+            max_staleness_secs: 60,
+            max_confidence_bps: 100,
+            min_lamports: 2,
+            max_lamports: 1,
+        });
+
+        assert_eq!(
+            config.violations(),
+            vec![
+                ConfigViolation::ZeroKey {
+                    field: ConfigField::Admin
+                },
+                ConfigViolation::DelayOutOfRange {
+                    field: ConfigField::AdminDelay,
+                    delay: -1
+                },
+                ConfigViolation::BpsOutOfRange {
+                    field: ConfigField::LoyaltyTiers,
+                    bps: 10_001
+                },
+                ConfigViolation::UnorderedLoyaltyTiers,
+                ConfigViolation::InvertedFeeBounds {
+                    min_lamports: 2,
+                    max_lamports: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn checked_sum_refuses_overflow() {
+        assert_eq!(checked_sum(&[1, 2, 3]), Ok(6));
+        assert_eq!(
+            checked_sum(&[u64::MAX, TX_FEE_HEADROOM]),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn search_key_normalizes() {
+        assert_eq!(
+            search_key::<SYMBOL_LEN>(" smpl "),
+            Some(*b"SMPL\0\0\0\0\0\0")
+        );
+        assert_eq!(search_key::<SYMBOL_LEN>("   "), None);
+        assert_eq!(search_key::<SYMBOL_LEN>("ELEVENCHARS"), None);
+    }
+
+    #[test]
+    fn versioned_is_capped_by_the_wrapped_instruction() {
+        // GetStats takes no arguments, so one trailing byte is too many.
+        let data = [VERSIONED_TAG, 1, 0, 0, 0, 45, 0];
+        assert_eq!(
+            process_instruction(&Pubkey::new_unique(), &[], &data),
+            Err(TokenInfoError::InstructionTooLarge { len: 2, max: 1 }.into())
+        );
+    }
+
+    #[test]
+    fn nested_versioned_is_refused() {
+        let data = [VERSIONED_TAG, 1, 0, 0, 0, VERSIONED_TAG, 1, 0, 0, 0, 47];
+        assert_eq!(
+            process_instruction(&Pubkey::new_unique(), &[], &data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn decodes_legacy_two_byte_header() {
+        let mut data = vec![MAGIC_BYTE, LEGACY_DATA_VERSION];
+        TokenInfo::V1(v1_record()).serialize(&mut data).unwrap();
+
+        let Some(TokenInfo::V1(info)) = TokenInfo::from_account_data(&data) else {
+            panic!("legacy record didn't decode");
+        };
+        assert_eq!(info.description, "Legacy token");
+
+        let header = AccountHeader::read(&data).unwrap();
+        assert_eq!(header.schema_version, LEGACY_DATA_VERSION);
+        assert_eq!(header.written_at_slot, 0);
+    }
+
+    #[test]
+    fn decodes_stamped_header() {
+        let header = AccountHeader {
+            magic: MAGIC_BYTE,
+            schema_version: DATA_VERSION,
+            written_at_slot: 42,
+        };
+        let mut data = borsh::to_vec(&header).unwrap();
+        TokenInfo::V1(v1_record()).serialize(&mut data).unwrap();
+        // Reserved headroom.
+        data.resize(data.len() + 16, 0);
+
+        assert!(matches!(
+            TokenInfo::from_account_data(&data),
+            Some(TokenInfo::V1(_))
+        ));
+        assert_eq!(AccountHeader::read(&data), Some(header));
+    }
+}
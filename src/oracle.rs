@@ -0,0 +1,120 @@
+//! Reads Pyth `PriceUpdateV2` accounts (pull oracle) without the Pyth SDK.
+//! Only fully verified updates are accepted.
+
+use alloc::format;
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey, pubkey::Pubkey,
+};
+
+use crate::TokenInfoError;
+
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// `sha256("account:PriceUpdateV2")[..8]`.
+pub const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Pyth SOL/USD feed id.
+pub const SOL_USD_FEED_ID: [u8; 32] = [
+    0xef, 0x0d, 0x8b, 0x6f, 0xda, 0x2c, 0xeb, 0xa4, 0x1d, 0xa1, 0x5d, 0x40, 0x95, 0xd1, 0xda, 0x39,
+    0x2a, 0x0d, 0x2f, 0x8e, 0xd0, 0xc6, 0xc7, 0xbc, 0x0f, 0x4c, 0xfa, 0xc8, 0xc2, 0x80, 0xb5, 0x6d,
+];
+
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+// discriminator + write authority
+const VERIFICATION_LEVEL_OFFSET: usize = 8 + 32;
+const PRICE_MESSAGE_LEN: usize = 32 + 8 + 8 + 4 + 8;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PriceUpdate {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+pub fn read_price_update(account: &AccountInfo) -> Result<PriceUpdate, ProgramError> {
+    if *account.owner != PYTH_RECEIVER_PROGRAM_ID {
+        msg!(
+            "[Error] Price account {:?} is not owned by the Pyth receiver",
+            account.key
+        );
+        return Err(TokenInfoError::InvalidPriceFeed.into());
+    }
+
+    let data = account.data.borrow();
+    let offset = VERIFICATION_LEVEL_OFFSET + 1;
+    if data.len() < offset + PRICE_MESSAGE_LEN || data[..8] != PRICE_UPDATE_V2_DISCRIMINATOR {
+        msg!("[Error] Price account is not a PriceUpdateV2");
+        return Err(TokenInfoError::InvalidPriceFeed.into());
+    }
+    // `Partial { num_signatures }` carries an extra byte; `Full` doesn't.
+    if data[VERIFICATION_LEVEL_OFFSET] != VERIFICATION_LEVEL_FULL {
+        msg!("[Error] Price update is not fully verified");
+        return Err(TokenInfoError::InvalidPriceFeed.into());
+    }
+
+    let message = &data[offset..offset + PRICE_MESSAGE_LEN];
+    let mut feed_id = [0u8; 32];
+    feed_id.copy_from_slice(&message[..32]);
+    Ok(PriceUpdate {
+        feed_id,
+        price: i64::from_le_bytes(message[32..40].try_into().unwrap()),
+        conf: u64::from_le_bytes(message[40..48].try_into().unwrap()),
+        exponent: i32::from_le_bytes(message[48..52].try_into().unwrap()),
+        publish_time: i64::from_le_bytes(message[52..60].try_into().unwrap()),
+    })
+}
+
+/// Converts `micro_usd` (1e-6 USD) to lamports at the given SOL/USD price.
+/// `None` on a non-positive price or overflow.
+pub fn micro_usd_to_lamports(micro_usd: u64, price: &PriceUpdate) -> Option<u64> {
+    if price.price <= 0 {
+        return None;
+    }
+    // lamports = micro_usd * 1e9 / 1e6 / (price * 10^exponent)
+    let scale = 10u128.checked_pow(price.exponent.unsigned_abs())?;
+    let numerator = (micro_usd as u128).checked_mul(1_000)?;
+    let lamports = if price.exponent < 0 {
+        numerator.checked_mul(scale)? / price.price as u128
+    } else {
+        numerator / (price.price as u128).checked_mul(scale)?
+    };
+    u64::try_from(lamports).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(price: i64, exponent: i32) -> PriceUpdate {
+        PriceUpdate {
+            feed_id: SOL_USD_FEED_ID,
+            price,
+            conf: 0,
+            exponent,
+            publish_time: 0,
+        }
+    }
+
+    #[test]
+    fn converts_at_the_feed_price() {
+        // $1 at $150/SOL, with Pyth's usual exponent and without one.
+        assert_eq!(
+            micro_usd_to_lamports(1_000_000, &price(15_000_000_000, -8)),
+            Some(6_666_666)
+        );
+        assert_eq!(
+            micro_usd_to_lamports(1_000_000, &price(150, 0)),
+            Some(6_666_666)
+        );
+    }
+
+    #[test]
+    fn rejects_unusable_prices() {
+        assert_eq!(micro_usd_to_lamports(1_000_000, &price(0, -8)), None);
+        assert_eq!(micro_usd_to_lamports(1_000_000, &price(-1, -8)), None);
+        // Doesn't fit in a u64.
+        assert_eq!(micro_usd_to_lamports(u64::MAX, &price(1, -8)), None);
+    }
+}
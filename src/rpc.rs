@@ -0,0 +1,208 @@
+//! Async read helpers for backends talking to an RPC node. They wrap the PDA
+//! derivation, `getProgramAccounts` filters and Borsh decoding of info
+//! records.
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use solana_account_decoder_client_types::{UiAccountEncoding, UiDataSliceConfig};
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    client_error::Error as ClientError,
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, RpcFilterType},
+};
+
+use crate::{
+    AccountHeader, MAGIC_BYTE, NAME_LEN, SYMBOL_LEN, TokenInfo, find_info_account, search_key,
+};
+
+/// Offset of the `TokenInfo` enum tag, right after the `AccountHeader`. V2
+/// records are only written with it, so the V2 filters below can rely on it.
+pub const INFO_VARIANT_OFFSET: usize = AccountHeader::LEN;
+/// `TokenInfoV2::mint`, right after the enum tag.
+pub const INFO_V2_MINT_OFFSET: usize = INFO_VARIANT_OFFSET + 1;
+pub const INFO_V2_CREATOR_OFFSET: usize = INFO_V2_MINT_OFFSET + 32;
+pub const INFO_V2_PAYER_OFFSET: usize = INFO_V2_CREATOR_OFFSET + 32;
+/// Past payer: three timestamps/slots, decimals, supply, verified,
+/// verified_at, challenged, reserved_bytes and endorsements.
+pub const INFO_V2_SYMBOL_OFFSET: usize = INFO_V2_PAYER_OFFSET + 32 + 55;
+pub const INFO_V2_NAME_OFFSET: usize = INFO_V2_SYMBOL_OFFSET + SYMBOL_LEN;
+/// The `Option` tag of `TokenInfoV2::origin`, followed by the key when set.
+pub const INFO_V2_ORIGIN_OFFSET: usize = INFO_V2_NAME_OFFSET + NAME_LEN;
+
+const INFO_V2_VARIANT: u8 = 1;
+/// `getMultipleAccounts` limit.
+pub const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(Debug)]
+pub enum FetchError {
+    Rpc(Box<ClientError>),
+    InvalidAccountData(Pubkey),
+}
+
+impl From<ClientError> for FetchError {
+    fn from(e: ClientError) -> Self {
+        FetchError::Rpc(Box::new(e))
+    }
+}
+
+/// Decodes the raw data of an info account, legacy records included.
+pub fn decode_token_info(data: &[u8]) -> Option<TokenInfo> {
+    if data.first() != Some(&MAGIC_BYTE) {
+        return None;
+    }
+    TokenInfo::from_account_data(data)
+}
+
+/// The info record for `mint` under `tenant`, or `None` if it was never
+/// created.
+pub async fn fetch_token_info(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Option<TokenInfo>, FetchError> {
+    let (address, _) = find_info_account(tenant, mint, program_id);
+    let response = rpc
+        .get_account_with_commitment(&address, rpc.commitment())
+        .await?;
+    match response.value {
+        Some(account) => decode_token_info(&account.data)
+            .map(Some)
+            .ok_or(FetchError::InvalidAccountData(address)),
+        None => Ok(None),
+    }
+}
+
+/// V2 records created by `creator`, found with a `memcmp` on the fixed header.
+pub async fn fetch_infos_by_creator(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    creator: &Pubkey,
+) -> Result<Vec<(Pubkey, TokenInfo)>, FetchError> {
+    fetch_v2_matching(rpc, program_id, INFO_V2_CREATOR_OFFSET, creator.as_ref()).await
+}
+
+/// V2 records whose symbol equals `symbol` once normalized by `search_key`.
+/// Empty if `symbol` can't be a search key.
+pub async fn fetch_infos_by_symbol(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    symbol: &str,
+) -> Result<Vec<(Pubkey, TokenInfo)>, FetchError> {
+    let Some(key) = search_key::<SYMBOL_LEN>(symbol) else {
+        return Ok(Vec::new());
+    };
+    fetch_v2_matching(rpc, program_id, INFO_V2_SYMBOL_OFFSET, &key).await
+}
+
+/// As `fetch_infos_by_symbol`, for the name.
+pub async fn fetch_infos_by_name(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    name: &str,
+) -> Result<Vec<(Pubkey, TokenInfo)>, FetchError> {
+    let Some(key) = search_key::<NAME_LEN>(name) else {
+        return Ok(Vec::new());
+    };
+    fetch_v2_matching(rpc, program_id, INFO_V2_NAME_OFFSET, &key).await
+}
+
+/// V2 records submitted through the launchpad `origin`.
+pub async fn fetch_infos_by_origin(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    origin: &Pubkey,
+) -> Result<Vec<(Pubkey, TokenInfo)>, FetchError> {
+    let mut bytes = vec![1];
+    bytes.extend_from_slice(origin.as_ref());
+    fetch_v2_matching(rpc, program_id, INFO_V2_ORIGIN_OFFSET, &bytes).await
+}
+
+async fn fetch_v2_matching(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    offset: usize,
+    bytes: &[u8],
+) -> Result<Vec<(Pubkey, TokenInfo)>, FetchError> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(v2_filters(offset, bytes)),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let accounts = rpc
+        .get_program_accounts_with_config(program_id, config)
+        .await?;
+
+    accounts
+        .into_iter()
+        .map(|(address, account)| {
+            decode_token_info(&account.data)
+                .map(|info| (address, info))
+                .ok_or(FetchError::InvalidAccountData(address))
+        })
+        .collect()
+}
+
+/// Every info record, V1 and V2. Addresses are listed first with an empty data
+/// slice, then fetched `page_size` at a time (capped at `MAX_PAGE_SIZE`), so no
+/// single response carries every record's data.
+pub async fn fetch_all_infos_paginated(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    page_size: usize,
+) -> Result<Vec<(Pubkey, TokenInfo)>, FetchError> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            vec![MAGIC_BYTE],
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: Some(UiDataSliceConfig {
+                offset: 0,
+                length: 0,
+            }),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut addresses: Vec<Pubkey> = rpc
+        .get_program_accounts_with_config(program_id, config)
+        .await?
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect();
+    addresses.sort();
+
+    let mut infos = Vec::with_capacity(addresses.len());
+    for page in addresses.chunks(page_size.clamp(1, MAX_PAGE_SIZE)) {
+        let accounts = rpc.get_multiple_accounts(page).await?;
+        for (address, account) in page.iter().zip(accounts) {
+            // Closed between the listing and this page.
+            let Some(account) = account else {
+                continue;
+            };
+            let info =
+                decode_token_info(&account.data).ok_or(FetchError::InvalidAccountData(*address))?;
+            infos.push((*address, info));
+        }
+    }
+    Ok(infos)
+}
+
+fn v2_filters(offset: usize, bytes: &[u8]) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![MAGIC_BYTE])),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            INFO_VARIANT_OFFSET,
+            vec![INFO_V2_VARIANT],
+        )),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes.to_vec())),
+    ]
+}
@@ -0,0 +1,53 @@
+//! Minimal read-only view of SPL token mints. Only the base `Mint` layout is
+//! parsed, which token-2022 shares (extensions live after it).
+
+use alloc::format;
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey, pubkey::Pubkey,
+};
+
+use crate::TokenInfoError;
+
+pub const SPL_TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+pub const MINT_LEN: usize = 82;
+const SUPPLY_OFFSET: usize = 36;
+const DECIMALS_OFFSET: usize = 44;
+const IS_INITIALIZED_OFFSET: usize = 45;
+
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == SPL_TOKEN_PROGRAM_ID || *program_id == TOKEN_2022_PROGRAM_ID
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MintSnapshot {
+    pub decimals: u8,
+    pub supply: u64,
+}
+
+/// Reads decimals and supply from an initialized mint owned by spl-token or
+/// token-2022.
+pub fn read_mint(mint_account: &AccountInfo) -> Result<MintSnapshot, ProgramError> {
+    if !is_token_program(mint_account.owner) {
+        msg!(
+            "[Error] Mint {:?} is not owned by a token program",
+            mint_account.key
+        );
+        return Err(TokenInfoError::InvalidMint.into());
+    }
+
+    let data = mint_account.data.borrow();
+    if data.len() < MINT_LEN || data[IS_INITIALIZED_OFFSET] != 1 {
+        msg!("[Error] Mint {:?} is not initialized", mint_account.key);
+        return Err(TokenInfoError::InvalidMint.into());
+    }
+
+    let mut supply = [0u8; 8];
+    supply.copy_from_slice(&data[SUPPLY_OFFSET..SUPPLY_OFFSET + 8]);
+
+    Ok(MintSnapshot {
+        decimals: data[DECIMALS_OFFSET],
+        supply: u64::from_le_bytes(supply),
+    })
+}
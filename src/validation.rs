@@ -0,0 +1,1431 @@
+use alloc::format;
+use alloc::vec::Vec;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::instructions,
+};
+
+use crate::events::{self, Event};
+use crate::{
+    AUTHORITY, AccountHeader, Config, GLOBAL_TENANT, Operator, TokenInfoError,
+    find_blacklist_account, find_challenge_account, find_config_account,
+    find_creation_quota_account, find_endorsement_account, find_fee_stats_account,
+    find_info_account, find_link_attestations_account, find_listing_bond_account,
+    find_operator_account, find_receipt_account, find_sponsorship_vault, load_config,
+    load_operator,
+};
+
+/// Logs `Event::ValidationFailed` for `account` and hands `error` back, so
+/// SDKs can tell which account a simulation failed on without parsing `msg!`
+/// text.
+fn fail(account: &AccountInfo, error: ProgramError) -> ProgramError {
+    // A fixed-size event always serializes.
+    let _ = events::emit(&Event::ValidationFailed {
+        code: u64::from(error.clone()),
+        account: *account.key,
+    });
+    error
+}
+
+pub fn signer(account: &AccountInfo, name: &str) -> Result<(), ProgramError> {
+    if !account.is_signer {
+        msg!("[Error] {} is not signer", name);
+        return Err(fail(account, ProgramError::MissingRequiredSignature));
+    }
+    Ok(())
+}
+
+pub fn key(account: &AccountInfo, expected: &Pubkey, name: &str) -> Result<(), ProgramError> {
+    if account.key != expected {
+        msg!(
+            "[Error] {} mismatch. Expected: {:?}, got: {:?}",
+            name,
+            expected,
+            account.key
+        );
+        return Err(fail(account, ProgramError::InvalidArgument));
+    }
+    Ok(())
+}
+
+/// Checks the account sits at the given derived address and returns its bump.
+pub fn pda(account: &AccountInfo, derived: (Pubkey, u8), name: &str) -> Result<u8, ProgramError> {
+    key(account, &derived.0, name)?;
+    Ok(derived.1)
+}
+
+pub fn owner(account: &AccountInfo, expected: &Pubkey, name: &str) -> Result<(), ProgramError> {
+    if account.owner != expected {
+        msg!(
+            "[Error] {} owner mismatch. Expected: {:?}, got: {:?}",
+            name,
+            expected,
+            account.owner
+        );
+        return Err(fail(account, ProgramError::IllegalOwner));
+    }
+    Ok(())
+}
+
+pub fn system_program(account: &AccountInfo) -> Result<(), ProgramError> {
+    if !system_program::check_id(account.key) {
+        msg!("[Error] Invalid system program: {:?}", account.key);
+        return Err(fail(account, ProgramError::IncorrectProgramId));
+    }
+    Ok(())
+}
+
+pub fn authority(account: &AccountInfo) -> Result<(), ProgramError> {
+    signer(account, "Authority")?;
+    key(account, &AUTHORITY, "Authority")
+}
+
+/// Checked by create handlers once the config is loaded: the tenant's own
+/// authority, or `AUTHORITY` under `GLOBAL_TENANT`.
+pub fn create_authority(account: &AccountInfo, config: &Config) -> Result<(), ProgramError> {
+    signer(account, "Authority")?;
+    key(account, &config.create_authority(), "Authority")
+}
+
+/// A program-owned account whose header carries `magic` and, if given,
+/// `version`. Every state loader checks this before deserializing, so a
+/// foreign account can't be read as program state.
+pub fn state(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+    magic: u8,
+    version: Option<u8>,
+    name: &str,
+) -> Result<(), ProgramError> {
+    owner(account, program_id, name)?;
+    let data = account.data.borrow();
+    if data.len() < AccountHeader::LEN
+        || data[0] != magic
+        || version.is_some_and(|version| data[1] != version)
+    {
+        msg!("[Error] {} has unexpected header", name);
+        return Err(fail(account, ProgramError::InvalidAccountData));
+    }
+    Ok(())
+}
+
+/// A config account of any tenant. Returns the tenant, which seeds the other
+/// PDAs of the instruction.
+pub fn config(account: &AccountInfo, program_id: &Pubkey) -> Result<Pubkey, ProgramError> {
+    let tenant = load_config(program_id, account)?.tenant;
+    pda(
+        account,
+        find_config_account(&tenant, program_id),
+        "Config account",
+    )?;
+    Ok(tenant)
+}
+
+/// Checked by handlers once the config is loaded, since the receiver can be
+/// rotated.
+pub fn fee_receiver(account: &AccountInfo, config: &Config) -> Result<(), ProgramError> {
+    key(account, &config.fee_receiver, "Fee receiver")
+}
+
+/// An account about to be created through the system program: empty and
+/// still owned by it.
+pub fn uninitialized(account: &AccountInfo, name: &str) -> Result<(), ProgramError> {
+    if !account.data_is_empty() {
+        msg!("[Error] {} already initialized", name);
+        return Err(fail(account, TokenInfoError::AccountAlreadyExists.into()));
+    }
+    owner(account, &system_program::id(), name)
+}
+
+/// The launchpad a record names as its origin must sign, so no record can be
+/// attributed to a platform that didn't submit it. It may be passed anywhere
+/// after the fixed accounts.
+pub fn origin(accounts: &[AccountInfo], origin: &Pubkey) -> Result<(), ProgramError> {
+    if !accounts
+        .iter()
+        .any(|account| account.key == origin && account.is_signer)
+    {
+        msg!("[Error] Origin {:?} is not signer", origin);
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+/// The registry entry of a record's origin, found among the instruction's
+/// accounts by its address, with the payout account it names. `None` while
+/// the origin isn't a registered operator, so the whole fee goes to the fee
+/// receiver.
+pub fn operator<'a, 'info>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    tenant: &Pubkey,
+    origin: &Pubkey,
+) -> Result<Option<(Operator, &'a AccountInfo<'info>)>, ProgramError> {
+    let address = find_operator_account(tenant, origin, program_id).0;
+    let Some(account) = accounts.iter().find(|account| *account.key == address) else {
+        msg!("[Error] Operator account of origin {:?} missing", origin);
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+    let operator = load_operator(program_id, account)?;
+    let Some(payout) = accounts
+        .iter()
+        .find(|account| *account.key == operator.payout)
+    else {
+        msg!("[Error] Operator payout {:?} missing", operator.payout);
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if !payout.is_writable {
+        msg!("[Error] Operator payout is not writable");
+        return Err(fail(payout, ProgramError::InvalidArgument));
+    }
+    Ok(Some((operator, payout)))
+}
+
+/// Writable accounts that move lamports or state between each other must be
+/// distinct, or the second write would clobber the first.
+pub fn distinct<'a, 'info: 'a>(
+    accounts: impl IntoIterator<Item = &'a AccountInfo<'info>>,
+) -> Result<(), ProgramError> {
+    let accounts: Vec<&AccountInfo> = accounts.into_iter().collect();
+    for (index, account) in accounts.iter().enumerate() {
+        let key = account.key;
+        if let Some(first) = accounts[..index].iter().position(|other| other.key == key) {
+            msg!(
+                "[Error] Account {} duplicates account {}: {:?}",
+                index,
+                first,
+                key
+            );
+            return Err(fail(account, TokenInfoError::DuplicateAccount.into()));
+        }
+    }
+    Ok(())
+}
+
+/// The blacklist PDA for `mint` must be passed; the create fails if the
+/// program has initialized it.
+pub fn not_blacklisted(
+    program_id: &Pubkey,
+    tenant: &Pubkey,
+    blacklist: &AccountInfo,
+    mint: &AccountInfo,
+) -> Result<(), ProgramError> {
+    pda(
+        blacklist,
+        find_blacklist_account(tenant, mint.key, program_id),
+        "Blacklist entry",
+    )?;
+    if blacklist.owner == program_id && !blacklist.data_is_empty() {
+        msg!("[Error] Mint {:?} is blacklisted", mint.key);
+        return Err(TokenInfoError::MintBlacklisted.into());
+    }
+    Ok(())
+}
+
+/// `[payer, authority, mint, info, system_program, fee_receiver, (config,
+/// (price_update))]`, the account list of the original `CreateInfo`.
+pub struct CreateInfoLegacy<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    /// Checked by the handler, against the config if there is one.
+    pub authority: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub fee_receiver: &'a AccountInfo<'info>,
+    pub config: Option<&'a AccountInfo<'info>>,
+    pub price_update: Option<&'a AccountInfo<'info>>,
+    /// `GLOBAL_TENANT` without a config.
+    pub tenant: Pubkey,
+    pub info_bump: u8,
+}
+
+impl<'a, 'info> CreateInfoLegacy<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        let fee_receiver = next_account_info(accounts_iter)?;
+        let config = accounts_iter.next();
+        let price_update = accounts_iter.next();
+
+        signer(payer, "Payer")?;
+        self::system_program(system_program)?;
+        let tenant = match config {
+            Some(config) => self::config(config, program_id)?,
+            None => GLOBAL_TENANT,
+        };
+        let info_bump = pda(
+            info,
+            find_info_account(&tenant, mint.key, program_id),
+            "Info account",
+        )?;
+        uninitialized(info, "Info account")?;
+
+        Ok(Self {
+            payer,
+            authority,
+            mint,
+            info,
+            system_program,
+            fee_receiver,
+            config,
+            price_update,
+            tenant,
+            info_bump,
+        })
+    }
+}
+
+pub struct CreateInfo<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub creator: &'a AccountInfo<'info>,
+    /// Checked by the handler with `create_authority`.
+    pub authority: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub fee_receiver: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub blacklist: &'a AccountInfo<'info>,
+    pub fee_stats: &'a AccountInfo<'info>,
+    /// Written only while `Config::creator_daily_quota` is set.
+    pub quota: &'a AccountInfo<'info>,
+    pub receipt: &'a AccountInfo<'info>,
+    /// Set for `CreateInfoPrepaid`.
+    pub instructions_sysvar: Option<&'a AccountInfo<'info>>,
+    /// Only needed when the config prices the fee in USD.
+    pub price_update: Option<&'a AccountInfo<'info>>,
+    pub tenant: Pubkey,
+    pub info_bump: u8,
+    pub fee_stats_bump: u8,
+    pub quota_bump: u8,
+    pub receipt_bump: u8,
+}
+
+impl<'a, 'info> CreateInfo<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+        prepaid: bool,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::load_allow_existing(program_id, accounts, prepaid)?;
+        uninitialized(ctx.info, "Info account")?;
+        Ok(ctx)
+    }
+
+    /// Like `load`, but the info account may already exist.
+    pub fn load_allow_existing(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+        prepaid: bool,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let creator = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        let fee_receiver = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let blacklist = next_account_info(accounts_iter)?;
+        let fee_stats = next_account_info(accounts_iter)?;
+        let quota = next_account_info(accounts_iter)?;
+        let receipt = next_account_info(accounts_iter)?;
+        let instructions_sysvar = if prepaid {
+            let account = next_account_info(accounts_iter)?;
+            key(account, &instructions::ID, "Instructions sysvar")?;
+            Some(account)
+        } else {
+            None
+        };
+        let price_update = accounts_iter.next();
+
+        signer(payer, "Payer")?;
+        signer(creator, "Creator")?;
+        self::system_program(system_program)?;
+        let tenant = self::config(config, program_id)?;
+        not_blacklisted(program_id, &tenant, blacklist, mint)?;
+        let info_bump = pda(
+            info,
+            find_info_account(&tenant, mint.key, program_id),
+            "Info account",
+        )?;
+        let fee_stats_bump = pda(
+            fee_stats,
+            find_fee_stats_account(&tenant, payer.key, program_id),
+            "Fee stats account",
+        )?;
+        let quota_bump = pda(
+            quota,
+            find_creation_quota_account(&tenant, creator.key, program_id),
+            "Creation quota account",
+        )?;
+        let receipt_bump = pda(
+            receipt,
+            find_receipt_account(&tenant, payer.key, mint.key, program_id),
+            "Receipt",
+        )?;
+
+        Ok(Self {
+            payer,
+            creator,
+            authority,
+            mint,
+            info,
+            system_program,
+            fee_receiver,
+            config,
+            blacklist,
+            fee_stats,
+            quota,
+            receipt,
+            instructions_sysvar,
+            price_update,
+            tenant,
+            info_bump,
+            fee_stats_bump,
+            quota_bump,
+            receipt_bump,
+        })
+    }
+}
+
+pub struct CreateInfoSponsored<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    /// Checked by the handler with `create_authority`.
+    pub authority: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub fee_receiver: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub blacklist: &'a AccountInfo<'info>,
+    /// Written only while `Config::creator_daily_quota` is set; the vault
+    /// funds it.
+    pub quota: &'a AccountInfo<'info>,
+    pub price_update: Option<&'a AccountInfo<'info>>,
+    pub tenant: Pubkey,
+    pub info_bump: u8,
+    pub vault_bump: u8,
+    pub quota_bump: u8,
+}
+
+impl<'a, 'info> CreateInfoSponsored<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let creator = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        let fee_receiver = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let vault = next_account_info(accounts_iter)?;
+        let blacklist = next_account_info(accounts_iter)?;
+        let quota = next_account_info(accounts_iter)?;
+        let price_update = accounts_iter.next();
+
+        signer(creator, "Creator")?;
+        self::system_program(system_program)?;
+        let tenant = self::config(config, program_id)?;
+        let vault_bump = pda(
+            vault,
+            find_sponsorship_vault(&tenant, program_id),
+            "Sponsorship vault",
+        )?;
+        not_blacklisted(program_id, &tenant, blacklist, mint)?;
+        let info_bump = pda(
+            info,
+            find_info_account(&tenant, mint.key, program_id),
+            "Info account",
+        )?;
+        uninitialized(info, "Info account")?;
+        let quota_bump = pda(
+            quota,
+            find_creation_quota_account(&tenant, creator.key, program_id),
+            "Creation quota account",
+        )?;
+
+        Ok(Self {
+            creator,
+            authority,
+            mint,
+            info,
+            system_program,
+            fee_receiver,
+            config,
+            vault,
+            blacklist,
+            quota,
+            price_update,
+            tenant,
+            info_bump,
+            vault_bump,
+            quota_bump,
+        })
+    }
+}
+
+pub struct InitConfig<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> InitConfig<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(payer, "Payer")?;
+        self::authority(authority)?;
+        self::system_program(system_program)?;
+        pda(
+            config,
+            find_config_account(&GLOBAL_TENANT, program_id),
+            "Config account",
+        )?;
+        uninitialized(config, "Config account")?;
+
+        Ok(Self {
+            payer,
+            authority,
+            config,
+            system_program,
+        })
+    }
+}
+
+/// `[payer, authority, tenant, config, system_program]`. The tenant signs so
+/// nobody else can claim its namespace.
+pub struct InitTenantConfig<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub tenant: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> InitTenantConfig<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        let tenant = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(payer, "Payer")?;
+        self::authority(authority)?;
+        signer(tenant, "Tenant")?;
+        if *tenant.key == GLOBAL_TENANT {
+            msg!("[Error] The global tenant is initialized through InitConfig");
+            return Err(ProgramError::InvalidArgument);
+        }
+        self::system_program(system_program)?;
+        pda(
+            config,
+            find_config_account(tenant.key, program_id),
+            "Config account",
+        )?;
+        uninitialized(config, "Config account")?;
+
+        Ok(Self {
+            payer,
+            authority,
+            tenant,
+            config,
+            system_program,
+        })
+    }
+}
+
+/// Admin-only config mutation: `[admin, config]`. The admin key itself is
+/// compared against the stored config by the handler once it is loaded.
+pub struct AdminConfig<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AdminConfig<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+
+        Ok(Self { admin, config })
+    }
+}
+
+/// Admin config mutation that may change the config's size: `[admin, config,
+/// system_program]`. The admin funds any growth.
+pub struct AdminConfigRealloc<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AdminConfigRealloc<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            admin,
+            config,
+            system_program,
+        })
+    }
+}
+
+/// `[funder, vault, system_program, config]`; the config picks the tenant whose
+/// vault is funded.
+pub struct FundSponsorship<'a, 'info> {
+    pub funder: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> FundSponsorship<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let funder = next_account_info(accounts_iter)?;
+        let vault = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+
+        signer(funder, "Funder")?;
+        let tenant = self::config(config, program_id)?;
+        pda(
+            vault,
+            find_sponsorship_vault(&tenant, program_id),
+            "Sponsorship vault",
+        )?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            funder,
+            vault,
+            system_program,
+            config,
+        })
+    }
+}
+
+/// Creator-signed change to an existing record: `[creator, info, config,
+/// system_program]`. The creator pays for any account growth.
+pub struct UpdateInfo<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> UpdateInfo<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let creator = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(creator, "Creator")?;
+        owner(info, program_id, "Info account")?;
+        self::config(config, program_id)?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            creator,
+            info,
+            config,
+            system_program,
+        })
+    }
+}
+
+/// `[admin, config, mint, blacklist, system_program]` for adding or removing
+/// a blacklist entry.
+pub struct Blacklist<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub blacklist: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub tenant: Pubkey,
+    pub blacklist_bump: u8,
+}
+
+impl<'a, 'info> Blacklist<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let blacklist = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        let tenant = self::config(config, program_id)?;
+        let blacklist_bump = pda(
+            blacklist,
+            find_blacklist_account(&tenant, mint.key, program_id),
+            "Blacklist entry",
+        )?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            admin,
+            config,
+            mint,
+            blacklist,
+            system_program,
+            tenant,
+            blacklist_bump,
+        })
+    }
+}
+
+/// `[admin, config, operator, operator_account, system_program]`.
+pub struct OperatorEntry<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    pub operator_account: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub tenant: Pubkey,
+    pub operator_bump: u8,
+}
+
+impl<'a, 'info> OperatorEntry<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let operator = next_account_info(accounts_iter)?;
+        let operator_account = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        let tenant = self::config(config, program_id)?;
+        let operator_bump = pda(
+            operator_account,
+            find_operator_account(&tenant, operator.key, program_id),
+            "Operator account",
+        )?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            admin,
+            config,
+            operator,
+            operator_account,
+            system_program,
+            tenant,
+            operator_bump,
+        })
+    }
+}
+
+/// `[candidate, config, system_program]`: the proposed fee receiver signs to
+/// take over, proving the key is controlled.
+pub struct AcceptFeeReceiver<'a, 'info> {
+    pub candidate: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AcceptFeeReceiver<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let candidate = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(candidate, "Fee receiver candidate")?;
+        self::config(config, program_id)?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            candidate,
+            config,
+            system_program,
+        })
+    }
+}
+
+/// `[admin, config, pending_action, system_program]`. The pending action's
+/// address depends on the config's action counter, so the handler checks it.
+pub struct QueueAdminAction<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub pending_action: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> QueueAdminAction<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let pending_action = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            admin,
+            config,
+            pending_action,
+            system_program,
+        })
+    }
+}
+
+/// `[executor, config, pending_action, queued_by, system_program]`. Anyone can
+/// execute once the delay has passed; the executor funds any config growth and
+/// the rent goes back to whoever queued the action.
+pub struct ExecuteAdminAction<'a, 'info> {
+    pub executor: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub pending_action: &'a AccountInfo<'info>,
+    pub queued_by: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ExecuteAdminAction<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let executor = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let pending_action = next_account_info(accounts_iter)?;
+        let queued_by = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(executor, "Executor")?;
+        self::config(config, program_id)?;
+        owner(pending_action, program_id, "Pending action account")?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            executor,
+            config,
+            pending_action,
+            queued_by,
+            system_program,
+        })
+    }
+}
+
+/// `[admin, config, pending_action, queued_by]`.
+pub struct CancelAdminAction<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub pending_action: &'a AccountInfo<'info>,
+    pub queued_by: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CancelAdminAction<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let pending_action = next_account_info(accounts_iter)?;
+        let queued_by = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        owner(pending_action, program_id, "Pending action account")?;
+
+        Ok(Self {
+            admin,
+            config,
+            pending_action,
+            queued_by,
+        })
+    }
+}
+
+/// `[admin, config, system_program, ..remaining]` for admin instructions that
+/// operate on a variable number of accounts.
+pub struct AdminBatch<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub remaining: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AdminBatch<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let [admin, config, system_program, remaining @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        self::system_program(system_program)?;
+        distinct(remaining)?;
+
+        Ok(Self {
+            admin,
+            config,
+            system_program,
+            remaining,
+        })
+    }
+}
+
+/// `[attester, info, config, attestations, system_program]`. The attester,
+/// admin or `Config::link_attester`, funds the attestations account.
+pub struct AttestLink<'a, 'info> {
+    pub attester: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub attestations: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub attestations_bump: u8,
+}
+
+impl<'a, 'info> AttestLink<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let attester = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let attestations = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(attester, "Attester")?;
+        owner(info, program_id, "Info account")?;
+        self::config(config, program_id)?;
+        let attestations_bump = pda(
+            attestations,
+            find_link_attestations_account(info.key, program_id),
+            "Link attestations account",
+        )?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            attester,
+            info,
+            config,
+            attestations,
+            system_program,
+            attestations_bump,
+        })
+    }
+}
+
+/// `[admin, config, fee_receiver, payer, receipt, system_program]`. The fee
+/// receiver signs since the refund comes out of its balance.
+pub struct RefundReceipt<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub fee_receiver: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub receipt: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> RefundReceipt<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let fee_receiver = next_account_info(accounts_iter)?;
+        let payer = next_account_info(accounts_iter)?;
+        let receipt = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        signer(fee_receiver, "Fee receiver")?;
+        owner(receipt, program_id, "Receipt")?;
+        self::system_program(system_program)?;
+        distinct([fee_receiver, payer, receipt])?;
+
+        Ok(Self {
+            admin,
+            config,
+            fee_receiver,
+            payer,
+            receipt,
+            system_program,
+        })
+    }
+}
+
+/// `[admin, config, vault, payer, receipt, system_program]`.
+pub struct RefundFee<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub receipt: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub vault_bump: u8,
+}
+
+impl<'a, 'info> RefundFee<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let vault = next_account_info(accounts_iter)?;
+        let payer = next_account_info(accounts_iter)?;
+        let receipt = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        let tenant = self::config(config, program_id)?;
+        let vault_bump = pda(
+            vault,
+            find_sponsorship_vault(&tenant, program_id),
+            "Sponsorship vault",
+        )?;
+        owner(receipt, program_id, "Receipt")?;
+        self::system_program(system_program)?;
+        distinct([vault, payer, receipt])?;
+
+        Ok(Self {
+            admin,
+            config,
+            vault,
+            payer,
+            receipt,
+            system_program,
+            vault_bump,
+        })
+    }
+}
+
+/// `[info]`.
+pub struct EmitSnapshot<'a, 'info> {
+    pub info: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> EmitSnapshot<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let info = next_account_info(accounts_iter)?;
+
+        owner(info, program_id, "Info account")?;
+
+        Ok(Self { info })
+    }
+}
+
+/// `[config]`.
+pub struct ValidateConfig<'a, 'info> {
+    pub config: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ValidateConfig<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let config = next_account_info(accounts_iter)?;
+
+        self::config(config, program_id)?;
+
+        Ok(Self { config })
+    }
+}
+
+/// `[config, sponsorship_vault]`.
+pub struct GetStats<'a, 'info> {
+    pub config: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> GetStats<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let config = next_account_info(accounts_iter)?;
+        let vault = next_account_info(accounts_iter)?;
+
+        let tenant = self::config(config, program_id)?;
+        pda(
+            vault,
+            find_sponsorship_vault(&tenant, program_id),
+            "Sponsorship vault",
+        )?;
+
+        Ok(Self { config, vault })
+    }
+}
+
+/// `[info, listing_bond]`.
+pub struct GetMintStats<'a, 'info> {
+    pub info: &'a AccountInfo<'info>,
+    pub listing_bond: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> GetMintStats<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let info = next_account_info(accounts_iter)?;
+        let listing_bond = next_account_info(accounts_iter)?;
+
+        pda(
+            listing_bond,
+            find_listing_bond_account(info.key, program_id),
+            "Listing bond account",
+        )?;
+
+        Ok(Self { info, listing_bond })
+    }
+}
+
+/// `[endorser, info, endorsement, system_program]`. The endorsement marker must
+/// not exist yet, which is what limits each wallet to one endorsement.
+pub struct Endorse<'a, 'info> {
+    pub endorser: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub endorsement: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub endorsement_bump: u8,
+}
+
+impl<'a, 'info> Endorse<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let endorser = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let endorsement = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(endorser, "Endorser")?;
+        owner(info, program_id, "Info account")?;
+        let endorsement_bump = pda(
+            endorsement,
+            find_endorsement_account(info.key, endorser.key, program_id),
+            "Endorsement",
+        )?;
+        uninitialized(endorsement, "Endorsement")?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            endorser,
+            info,
+            endorsement,
+            system_program,
+            endorsement_bump,
+        })
+    }
+}
+
+/// `[challenger, info, config, challenge, system_program]`. The challenger
+/// funds the challenge account and its bond.
+pub struct ChallengeVerification<'a, 'info> {
+    pub challenger: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub challenge: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub challenge_bump: u8,
+}
+
+impl<'a, 'info> ChallengeVerification<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let challenger = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let challenge = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(challenger, "Challenger")?;
+        owner(info, program_id, "Info account")?;
+        self::config(config, program_id)?;
+        let challenge_bump = pda(
+            challenge,
+            find_challenge_account(info.key, program_id),
+            "Challenge account",
+        )?;
+        uninitialized(challenge, "Challenge account")?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            challenger,
+            info,
+            config,
+            challenge,
+            system_program,
+            challenge_bump,
+        })
+    }
+}
+
+/// `[admin, config, info, challenge, challenger, fee_receiver]`.
+pub struct ResolveChallenge<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub challenge: &'a AccountInfo<'info>,
+    pub challenger: &'a AccountInfo<'info>,
+    pub fee_receiver: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ResolveChallenge<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let challenge = next_account_info(accounts_iter)?;
+        let challenger = next_account_info(accounts_iter)?;
+        let fee_receiver = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        owner(info, program_id, "Info account")?;
+        owner(challenge, program_id, "Challenge account")?;
+        pda(
+            challenge,
+            find_challenge_account(info.key, program_id),
+            "Challenge account",
+        )?;
+        distinct([info, challenge, challenger, fee_receiver])?;
+
+        Ok(Self {
+            admin,
+            config,
+            info,
+            challenge,
+            challenger,
+            fee_receiver,
+        })
+    }
+}
+
+/// `[creator, info, config, bond, system_program]`.
+pub struct PostListingBond<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub bond: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub bond_bump: u8,
+}
+
+impl<'a, 'info> PostListingBond<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let creator = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let bond = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        signer(creator, "Creator")?;
+        owner(info, program_id, "Info account")?;
+        self::config(config, program_id)?;
+        let bond_bump = pda(
+            bond,
+            find_listing_bond_account(info.key, program_id),
+            "Listing bond",
+        )?;
+        uninitialized(bond, "Listing bond")?;
+        self::system_program(system_program)?;
+
+        Ok(Self {
+            creator,
+            info,
+            config,
+            bond,
+            system_program,
+            bond_bump,
+        })
+    }
+}
+
+/// `[creator, info, config, bond]`.
+pub struct ReclaimBond<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub bond: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ReclaimBond<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let creator = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let bond = next_account_info(accounts_iter)?;
+
+        signer(creator, "Creator")?;
+        self::config(config, program_id)?;
+        // Reclaiming and forfeiting close the bond.
+        if bond.data_is_empty() {
+            msg!("[Error] Listing bond already released");
+            return Err(fail(bond, TokenInfoError::BondReleased.into()));
+        }
+        owner(bond, program_id, "Listing bond")?;
+        pda(
+            bond,
+            find_listing_bond_account(info.key, program_id),
+            "Listing bond",
+        )?;
+
+        Ok(Self {
+            creator,
+            info,
+            config,
+            bond,
+        })
+    }
+}
+
+/// `[admin, config, info, bond, creator, fee_receiver]`.
+pub struct ForfeitBond<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub info: &'a AccountInfo<'info>,
+    pub bond: &'a AccountInfo<'info>,
+    pub creator: &'a AccountInfo<'info>,
+    pub fee_receiver: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ForfeitBond<'a, 'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let admin = next_account_info(accounts_iter)?;
+        let config = next_account_info(accounts_iter)?;
+        let info = next_account_info(accounts_iter)?;
+        let bond = next_account_info(accounts_iter)?;
+        let creator = next_account_info(accounts_iter)?;
+        let fee_receiver = next_account_info(accounts_iter)?;
+
+        signer(admin, "Admin")?;
+        self::config(config, program_id)?;
+        owner(bond, program_id, "Listing bond")?;
+        pda(
+            bond,
+            find_listing_bond_account(info.key, program_id),
+            "Listing bond",
+        )?;
+        distinct([bond, creator, fee_receiver])?;
+
+        Ok(Self {
+            admin,
+            config,
+            info,
+            bond,
+            creator,
+            fee_receiver,
+        })
+    }
+}
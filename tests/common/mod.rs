@@ -0,0 +1,252 @@
+//! Fixtures shared by the integration tests: accounts laid out exactly as the
+//! program stores them, and sample create arguments.
+// Each test crate uses its own subset.
+#![allow(dead_code)]
+
+use launch_lock::{
+    AUTHORITY, AdminAction, Config, CreateInfoArgs, DEFAULT_FEE_RECEIVER_DELAY,
+    DEFAULT_MAX_ACCOUNT_SIZE, DescriptionFormat, FEE_AMOUNT, FEE_RECEIVER, GLOBAL_TENANT,
+    ImageMetadata, Images, ImagesMetadata, LaunchTimeline, Link, ListingBond, Operator,
+    PendingAction, Receipt, TokenInfo, TokenInfoV2, find_info_account, fixtures,
+    process_instruction, search_key, token,
+};
+use solana_program_test::{ProgramTest, processor};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+pub const LAMPORTS: u64 = 10_000_000_000;
+
+/// Loads the SBF build when `SBF_OUT_DIR` (set by `cargo test-sbf`) points at
+/// it, the native processor otherwise.
+pub fn program_test(program_id: Pubkey) -> ProgramTest {
+    ProgramTest::new("launch_lock", program_id, processor!(process_instruction))
+}
+
+pub fn config_account(program_id: &Pubkey) -> Account {
+    config_account_with(program_id, sample_config())
+}
+
+pub fn sample_config() -> Config {
+    Config {
+        admin: AUTHORITY,
+        sponsorship_daily_cap: LAMPORTS,
+        sponsorship_day: 0,
+        sponsorship_spent_today: 0,
+        max_account_size: DEFAULT_MAX_ACCOUNT_SIZE,
+        allowed_description_formats: DescriptionFormat::ALL,
+        allowed_token_programs: vec![token::SPL_TOKEN_PROGRAM_ID],
+        allowed_mints: Vec::new(),
+        fee_receiver: FEE_RECEIVER,
+        fee_receiver_delay: DEFAULT_FEE_RECEIVER_DELAY,
+        pending_fee_receiver: None,
+        admin_delay: 0,
+        next_action_id: 0,
+        loyalty_tiers: Config::default_loyalty_tiers(),
+        usd_fee: None,
+        tenant: GLOBAL_TENANT,
+        creator_daily_quota: 10,
+        link_attester: None,
+        v1_cutover_slot: None,
+        verification_dispute_delay: 0,
+        challenge_bond: FEE_AMOUNT,
+        listing_bond_lock: 0,
+        total_creates: 0,
+        sponsored_creates: 0,
+        authority: None,
+    }
+}
+
+pub fn config_account_with(program_id: &Pubkey, config: Config) -> Account {
+    let data = fixtures::account_data(&config, 0);
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn mint_account() -> Account {
+    let mut data = vec![0u8; token::MINT_LEN];
+    data[36..44].copy_from_slice(&1_000_000_000_000_000u64.to_le_bytes());
+    data[44] = 9;
+    data[45] = 1;
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: token::SPL_TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn info_account(program_id: &Pubkey, mint: &Pubkey, creator: &Pubkey) -> Account {
+    info_account_with(program_id, sample_info(mint, creator))
+}
+
+pub fn sample_info(mint: &Pubkey, creator: &Pubkey) -> TokenInfoV2 {
+    TokenInfoV2 {
+        mint: *mint,
+        creator: *creator,
+        payer: *creator,
+        creation_timestamp: 0,
+        creation_slot: 0,
+        update_timestamp: 0,
+        mint_decimals: 9,
+        mint_supply_at_creation: 1_000_000_000_000_000,
+        verified: false,
+        verified_at: 0,
+        challenged: false,
+        reserved_bytes: 0,
+        endorsements: 0,
+        symbol: search_key("OLD").unwrap(),
+        name: search_key("Existing Token").unwrap(),
+        origin: None,
+        description_format: DescriptionFormat::PlainText,
+        timeline: LaunchTimeline::default(),
+        description: "An existing record".to_string(),
+        links: sample_links(),
+        images: Images {
+            icon: "https://example.com/icon.png".to_string(),
+            header: "https://example.com/header.png".to_string(),
+        },
+        image_metadata: ImagesMetadata::default(),
+    }
+}
+
+pub fn info_account_with(program_id: &Pubkey, info: TokenInfoV2) -> Account {
+    let data = fixtures::account_data(&TokenInfo::V2(info), 0);
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn sample_links() -> Vec<Link> {
+    vec![
+        Link {
+            label: "website".to_string(),
+            url: "https://example.com".to_string(),
+        },
+        Link {
+            label: "x".to_string(),
+            url: "https://x.com/example".to_string(),
+        },
+    ]
+}
+
+pub fn receipt_account(program_id: &Pubkey, payer: &Pubkey, mint: &Pubkey) -> Account {
+    let receipt = Receipt {
+        payer: *payer,
+        mint: *mint,
+        info_account: find_info_account(&GLOBAL_TENANT, mint, program_id).0,
+        fee: FEE_AMOUNT,
+        slot: 0,
+        refunded: 0,
+    };
+    let data = fixtures::account_data(&receipt, 0);
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn sample_args(description: &str) -> CreateInfoArgs {
+    CreateInfoArgs {
+        description: description.to_string(),
+        description_format: DescriptionFormat::Markdown,
+        links: sample_links(),
+        icon_uri: "https://example.com/icon.png".to_string(),
+        header_uri: "https://example.com/header.png".to_string(),
+        image_metadata: sample_image_metadata(),
+        reserve_bytes: 64,
+        symbol: Some("smpl".to_string()),
+        name: Some("Sample Token".to_string()),
+        origin: None,
+    }
+}
+
+pub fn sample_image_metadata() -> ImagesMetadata {
+    ImagesMetadata {
+        icon: ImageMetadata {
+            mime: Some("image/png".to_string()),
+            width: Some(256),
+            height: Some(256),
+            sha256: Some([7; 32]),
+        },
+        header: ImageMetadata {
+            mime: Some("image/webp".to_string()),
+            width: Some(1500),
+            height: Some(500),
+            sha256: Some([9; 32]),
+        },
+    }
+}
+
+pub fn pending_action_account(program_id: &Pubkey, action: AdminAction) -> Account {
+    let data = fixtures::account_data(
+        &PendingAction {
+            id: 0,
+            action,
+            queued_by: AUTHORITY,
+            queued_at: 0,
+            executable_at: 0,
+        },
+        0,
+    );
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn pending_verification(mint: &Pubkey) -> TokenInfoV2 {
+    let mut info = sample_info(mint, &Pubkey::new_unique());
+    info.verified = true;
+    info.verified_at = i64::MAX;
+    info
+}
+
+pub fn listing_bond_account(program_id: &Pubkey, creator: &Pubkey, mint: &Pubkey) -> Account {
+    let bond = ListingBond {
+        creator: *creator,
+        mint: *mint,
+        amount: FEE_AMOUNT,
+        posted_at: 0,
+    };
+    let data = fixtures::account_data(&bond, 0);
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn operator_account(program_id: &Pubkey, operator: &Pubkey, payout: &Pubkey) -> Account {
+    let data = fixtures::account_data(
+        &Operator {
+            operator: *operator,
+            payout: *payout,
+            share_bps: 2_000,
+        },
+        0,
+    );
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
@@ -0,0 +1,1633 @@
+//! Compute unit regression checks. `cargo test-sbf --features bench` runs them
+//! against the SBF build and enforces the budgets; plain `cargo test
+//! --features bench` runs the same flows on the native processor, whose unit
+//! counts don't mean anything, and only reports them.
+#![cfg(feature = "bench")]
+
+mod common;
+
+use common::*;
+use launch_lock::{
+    AUTHORITY, AdminAction, AllowList, BlacklistEntry, CreateInfoArgs, DEFAULT_LISTING_BOND_LOCK,
+    DescriptionFormat, FEE_AMOUNT, FEE_RECEIVER, GLOBAL_TENANT, LaunchTimeline, LinkStatus,
+    LoyaltyTier, MAX_CONFIG_DELAY, PROGRAM_API_VERSION, PendingFeeReceiver, SECONDS_PER_DAY,
+    UsdFee, VerificationChallenge, accounts::CreateInfoAccounts, client, compute_budget,
+    find_blacklist_account, find_challenge_account, find_config_account, find_info_account,
+    find_listing_bond_account, find_operator_account, find_pending_action_account,
+    find_receipt_account, find_sponsorship_vault, fixtures, oracle, token,
+};
+use solana_program_test::BanksClient;
+use solana_sdk::{
+    account::Account, hash::Hash, instruction::Instruction, message::Message, pubkey::Pubkey,
+    signature::Signer, system_instruction, system_program, transaction::Transaction,
+};
+
+fn sbf_build() -> bool {
+    std::env::var_os("SBF_OUT_DIR").is_some() || std::env::var_os("BPF_OUT_DIR").is_some()
+}
+
+// Simulation skips signature verification, which lets us include the
+// hardcoded authority as a signer without its key.
+async fn units_consumed(
+    banks_client: &mut BanksClient,
+    payer: &Pubkey,
+    instruction: Instruction,
+    recent_blockhash: Hash,
+) -> u64 {
+    transaction_units_consumed(banks_client, payer, &[instruction], recent_blockhash).await
+}
+
+/// Units of the whole transaction, for instructions that need a preceding one.
+async fn transaction_units_consumed(
+    banks_client: &mut BanksClient,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    recent_blockhash: Hash,
+) -> u64 {
+    let message = Message::new_with_blockhash(instructions, Some(payer), &recent_blockhash);
+    let simulation = banks_client
+        .simulate_transaction(Transaction::new_unsigned(message))
+        .await
+        .unwrap();
+    let details = simulation.simulation_details.unwrap();
+    if let Some(Err(err)) = simulation.result {
+        panic!("simulation failed: {err:?}\n{:#?}", details.logs);
+    }
+    details.units_consumed
+}
+
+fn assert_within_budget(name: &str, consumed: u64, budget: u32) {
+    println!("{name}: {consumed} / {budget} CU");
+    if !sbf_build() {
+        return;
+    }
+    assert!(
+        consumed <= budget as u64,
+        "{name} consumed {consumed} CU, budget is {budget}"
+    );
+}
+
+#[tokio::test]
+async fn create_info_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::create_info(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer.pubkey(),
+        &mint,
+        &FEE_RECEIVER,
+        &AUTHORITY,
+        "A token used to benchmark CreateInfo".to_string(),
+        sample_links(),
+        "https://example.com/icon.png".to_string(),
+        "https://example.com/header.png".to_string(),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("CreateInfo", consumed, compute_budget::CREATE_INFO);
+}
+
+#[tokio::test]
+async fn create_info_v2_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::create_info_v2(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer.pubkey(),
+        &payer.pubkey(),
+        &mint,
+        &FEE_RECEIVER,
+        &AUTHORITY,
+        sample_args("A token used to benchmark **CreateInfoV2**"),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("CreateInfoV2", consumed, compute_budget::CREATE_INFO_V2);
+}
+
+#[tokio::test]
+async fn create_info_prepaid_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let payment = system_instruction::transfer(&payer.pubkey(), &FEE_RECEIVER, FEE_AMOUNT);
+    let instruction = client::create_info_prepaid(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer.pubkey(),
+        &payer.pubkey(),
+        &mint,
+        &FEE_RECEIVER,
+        &AUTHORITY,
+        sample_args("A token used to benchmark CreateInfoPrepaid"),
+    );
+    let consumed = transaction_units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        &[payment, instruction],
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "CreateInfoPrepaid",
+        consumed,
+        compute_budget::CREATE_INFO_PREPAID,
+    );
+}
+
+#[tokio::test]
+async fn create_info_sponsored_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(
+        find_sponsorship_vault(&GLOBAL_TENANT, &program_id).0,
+        Account::new(LAMPORTS, 0, &system_program::id()),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::create_info_sponsored(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer.pubkey(),
+        &mint,
+        &FEE_RECEIVER,
+        &AUTHORITY,
+        sample_args("A token used to benchmark CreateInfoSponsored"),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "CreateInfoSponsored",
+        consumed,
+        compute_budget::CREATE_INFO_SPONSORED,
+    );
+}
+
+#[tokio::test]
+async fn init_config_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let (mut banks_client, payer, recent_blockhash) = program_test(program_id).start().await;
+
+    let instruction = client::init_config(&program_id, &payer.pubkey(), LAMPORTS);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("InitConfig", consumed, compute_budget::INIT_CONFIG);
+}
+
+#[tokio::test]
+async fn set_sponsorship_cap_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::set_sponsorship_cap(&program_id, &GLOBAL_TENANT, &AUTHORITY, LAMPORTS / 2);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetSponsorshipCap",
+        consumed,
+        compute_budget::SET_SPONSORSHIP_CAP,
+    );
+}
+
+#[tokio::test]
+async fn fund_sponsorship_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::fund_sponsorship(&program_id, &GLOBAL_TENANT, &payer.pubkey(), LAMPORTS / 10);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "FundSponsorship",
+        consumed,
+        compute_budget::FUND_SPONSORSHIP,
+    );
+}
+
+#[tokio::test]
+async fn set_max_account_size_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_max_account_size(&program_id, &GLOBAL_TENANT, &AUTHORITY, 2048);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetMaxAccountSize",
+        consumed,
+        compute_budget::SET_MAX_ACCOUNT_SIZE,
+    );
+}
+
+#[tokio::test]
+async fn set_allowed_description_formats_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_allowed_description_formats(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        vec![DescriptionFormat::PlainText],
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetAllowedDescriptionFormats",
+        consumed,
+        compute_budget::SET_ALLOWED_DESCRIPTION_FORMATS,
+    );
+}
+
+#[tokio::test]
+async fn update_timeline_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &creator),
+    );
+    program_test.add_account(creator, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let timeline = LaunchTimeline {
+        launch_ts: Some(1_800_000_000),
+        presale_start: Some(1_790_000_000),
+        presale_end: Some(1_795_000_000),
+        listing_ts: Some(1_800_000_600),
+    };
+    let instruction =
+        client::update_timeline(&program_id, &GLOBAL_TENANT, &creator, &mint, timeline);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("UpdateTimeline", consumed, compute_budget::UPDATE_TIMELINE);
+}
+
+#[tokio::test]
+async fn add_to_allow_list_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::add_to_allow_list(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        AllowList::Mints,
+        Pubkey::new_unique(),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "AddToAllowList",
+        consumed,
+        compute_budget::ADD_TO_ALLOW_LIST,
+    );
+}
+
+#[tokio::test]
+async fn remove_from_allow_list_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::remove_from_allow_list(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        AllowList::TokenPrograms,
+        token::SPL_TOKEN_PROGRAM_ID,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "RemoveFromAllowList",
+        consumed,
+        compute_budget::REMOVE_FROM_ALLOW_LIST,
+    );
+}
+
+#[tokio::test]
+async fn add_to_blacklist_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::add_to_blacklist(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &Pubkey::new_unique(),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("AddToBlacklist", consumed, compute_budget::ADD_TO_BLACKLIST);
+}
+
+#[tokio::test]
+async fn remove_from_blacklist_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let data = fixtures::account_data(
+        &BlacklistEntry {
+            mint,
+            added_timestamp: 0,
+        },
+        0,
+    );
+    program_test.add_account(
+        find_blacklist_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        Account {
+            lamports: LAMPORTS,
+            data,
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::remove_from_blacklist(&program_id, &GLOBAL_TENANT, &AUTHORITY, &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "RemoveFromBlacklist",
+        consumed,
+        compute_budget::REMOVE_FROM_BLACKLIST,
+    );
+}
+
+#[tokio::test]
+async fn propose_fee_receiver_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::propose_fee_receiver(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &Pubkey::new_unique(),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "ProposeFeeReceiver",
+        consumed,
+        compute_budget::PROPOSE_FEE_RECEIVER,
+    );
+}
+
+#[tokio::test]
+async fn accept_fee_receiver_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let candidate = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let mut config = sample_config();
+    config.pending_fee_receiver = Some(PendingFeeReceiver {
+        candidate,
+        executable_at: 0,
+    });
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account_with(&program_id, config),
+    );
+    program_test.add_account(candidate, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::accept_fee_receiver(&program_id, &GLOBAL_TENANT, &candidate);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "AcceptFeeReceiver",
+        consumed,
+        compute_budget::ACCEPT_FEE_RECEIVER,
+    );
+}
+
+#[tokio::test]
+async fn queue_admin_action_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::queue_admin_action(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        0,
+        AdminAction::AddToAllowList {
+            list: AllowList::Mints,
+            key: Pubkey::new_unique(),
+        },
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "QueueAdminAction",
+        consumed,
+        compute_budget::QUEUE_ADMIN_ACTION,
+    );
+}
+
+#[tokio::test]
+async fn execute_admin_action_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(
+        find_pending_action_account(&GLOBAL_TENANT, 0, &program_id).0,
+        pending_action_account(
+            &program_id,
+            AdminAction::AddToAllowList {
+                list: AllowList::Mints,
+                key: Pubkey::new_unique(),
+            },
+        ),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::execute_admin_action(&program_id, &GLOBAL_TENANT, &payer.pubkey(), 0, &AUTHORITY);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "ExecuteAdminAction",
+        consumed,
+        compute_budget::EXECUTE_ADMIN_ACTION,
+    );
+}
+
+#[tokio::test]
+async fn cancel_admin_action_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(
+        find_pending_action_account(&GLOBAL_TENANT, 0, &program_id).0,
+        pending_action_account(&program_id, AdminAction::SetSponsorshipCap { daily_cap: 0 }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::cancel_admin_action(&program_id, &GLOBAL_TENANT, &AUTHORITY, 0, &AUTHORITY);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "CancelAdminAction",
+        consumed,
+        compute_budget::CANCEL_ADMIN_ACTION,
+    );
+}
+
+#[tokio::test]
+async fn set_loyalty_tiers_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_loyalty_tiers(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        vec![
+            LoyaltyTier {
+                min_paid_creations: 5,
+                discount_bps: 500,
+            },
+            LoyaltyTier {
+                min_paid_creations: 20,
+                discount_bps: 1_500,
+            },
+            LoyaltyTier {
+                min_paid_creations: 100,
+                discount_bps: 5_000,
+            },
+        ],
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetLoyaltyTiers",
+        consumed,
+        compute_budget::SET_LOYALTY_TIERS,
+    );
+}
+
+#[tokio::test]
+async fn set_usd_fee_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_usd_fee(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        Some(UsdFee {
+            feed_id: oracle::SOL_USD_FEED_ID,
+            micro_usd: 15_000_000,
+            max_staleness_secs: 60,
+            max_confidence_bps: 100,
+            min_lamports: 10_000_000,
+            max_lamports: 1_000_000_000,
+        }),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("SetUsdFee", consumed, compute_budget::SET_USD_FEE);
+}
+
+#[tokio::test]
+async fn preview_create_info_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::preview_create_info(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer.pubkey(),
+        &payer.pubkey(),
+        &mint,
+        &FEE_RECEIVER,
+        &AUTHORITY,
+        sample_args("A token used to benchmark PreviewCreateInfo"),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "PreviewCreateInfo",
+        consumed,
+        compute_budget::PREVIEW_CREATE_INFO,
+    );
+}
+
+#[tokio::test]
+async fn create_info_idempotent_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::create_info_idempotent(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer.pubkey(),
+        &payer.pubkey(),
+        &mint,
+        &FEE_RECEIVER,
+        &AUTHORITY,
+        sample_args("A token used to benchmark CreateInfoIdempotent"),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "CreateInfoIdempotent",
+        consumed,
+        compute_budget::CREATE_INFO_IDEMPOTENT,
+    );
+}
+
+#[tokio::test]
+async fn set_verified_batch_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let info_accounts: Vec<Pubkey> = (0..4)
+        .map(|_| {
+            let mint = Pubkey::new_unique();
+            let address = find_info_account(&GLOBAL_TENANT, &mint, &program_id).0;
+            program_test.add_account(
+                address,
+                info_account(&program_id, &mint, &Pubkey::new_unique()),
+            );
+            address
+        })
+        .collect();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_verified_batch(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &info_accounts,
+        vec![true; info_accounts.len()],
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetVerifiedBatch",
+        consumed,
+        compute_budget::SET_VERIFIED_BATCH_PER_ACCOUNT * info_accounts.len() as u32,
+    );
+}
+
+#[tokio::test]
+async fn init_tenant_config_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let tenant = Pubkey::new_unique();
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::init_tenant_config(&program_id, &payer.pubkey(), &tenant, LAMPORTS, &tenant);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "InitTenantConfig",
+        consumed,
+        compute_budget::INIT_TENANT_CONFIG,
+    );
+}
+
+#[tokio::test]
+async fn set_creator_daily_quota_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_creator_daily_quota(&program_id, &GLOBAL_TENANT, &AUTHORITY, 20);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetCreatorDailyQuota",
+        consumed,
+        compute_budget::SET_CREATOR_DAILY_QUOTA,
+    );
+}
+
+#[tokio::test]
+async fn set_link_attester_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_link_attester(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        Some(Pubkey::new_unique()),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetLinkAttester",
+        consumed,
+        compute_budget::SET_LINK_ATTESTER,
+    );
+}
+
+#[tokio::test]
+async fn attest_link_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &Pubkey::new_unique()),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::attest_link(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &mint,
+        0,
+        LinkStatus::Broken,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("AttestLink", consumed, compute_budget::ATTEST_LINK);
+}
+
+#[tokio::test]
+async fn update_images_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &creator),
+    );
+    program_test.add_account(creator, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::update_images(
+        &program_id,
+        &GLOBAL_TENANT,
+        &creator,
+        &mint,
+        "https://example.com/icon-v2.png".to_string(),
+        "https://example.com/header-v2.png".to_string(),
+        sample_image_metadata(),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("UpdateImages", consumed, compute_budget::UPDATE_IMAGES);
+}
+
+#[tokio::test]
+async fn trim_info_account_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &creator),
+    );
+    program_test.add_account(creator, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::trim_info_account(&program_id, &GLOBAL_TENANT, &creator, &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "TrimInfoAccount",
+        consumed,
+        compute_budget::TRIM_INFO_ACCOUNT,
+    );
+}
+
+#[tokio::test]
+async fn refund_receipt_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    program_test.add_account(
+        FEE_RECEIVER,
+        Account::new(LAMPORTS, 0, &system_program::id()),
+    );
+    let payer_wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_receipt_account(&GLOBAL_TENANT, &payer_wallet, &mint, &program_id).0,
+        receipt_account(&program_id, &payer_wallet, &mint),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::refund_receipt(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &FEE_RECEIVER,
+        &payer_wallet,
+        &mint,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("RefundReceipt", consumed, compute_budget::REFUND_RECEIPT);
+}
+
+#[tokio::test]
+async fn refund_fee_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    program_test.add_account(
+        find_sponsorship_vault(&GLOBAL_TENANT, &program_id).0,
+        Account::new(LAMPORTS, 0, &system_program::id()),
+    );
+    let payer_wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_receipt_account(&GLOBAL_TENANT, &payer_wallet, &mint, &program_id).0,
+        receipt_account(&program_id, &payer_wallet, &mint),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::refund_fee(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &payer_wallet,
+        &mint,
+        FEE_AMOUNT / 2,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("RefundFee", consumed, compute_budget::REFUND_FEE);
+}
+
+#[tokio::test]
+async fn emit_snapshot_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &Pubkey::new_unique()),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::emit_snapshot(&program_id, &GLOBAL_TENANT, &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("EmitSnapshot", consumed, compute_budget::EMIT_SNAPSHOT);
+}
+
+#[tokio::test]
+async fn set_v1_cutover_slot_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::set_v1_cutover_slot(&program_id, &GLOBAL_TENANT, &AUTHORITY, Some(1_000_000));
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetV1CutoverSlot",
+        consumed,
+        compute_budget::SET_V1_CUTOVER_SLOT,
+    );
+}
+
+#[tokio::test]
+async fn endorse_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &Pubkey::new_unique()),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::endorse(&program_id, &GLOBAL_TENANT, &payer.pubkey(), &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("Endorse", consumed, compute_budget::ENDORSE);
+}
+
+#[tokio::test]
+async fn set_verification_dispute_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_verification_dispute(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        SECONDS_PER_DAY,
+        FEE_AMOUNT,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetVerificationDispute",
+        consumed,
+        compute_budget::SET_VERIFICATION_DISPUTE,
+    );
+}
+
+#[tokio::test]
+async fn challenge_verification_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account_with(&program_id, pending_verification(&mint)),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::challenge_verification(&program_id, &GLOBAL_TENANT, &payer.pubkey(), &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "ChallengeVerification",
+        consumed,
+        compute_budget::CHALLENGE_VERIFICATION,
+    );
+}
+
+#[tokio::test]
+async fn resolve_challenge_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    let info_address = find_info_account(&GLOBAL_TENANT, &mint, &program_id).0;
+    let mut info = pending_verification(&mint);
+    info.challenged = true;
+    program_test.add_account(info_address, info_account_with(&program_id, info));
+    let challenger = Pubkey::new_unique();
+    let challenge = VerificationChallenge {
+        challenger,
+        mint,
+        bond: FEE_AMOUNT,
+        challenged_at: 0,
+    };
+    let data = fixtures::account_data(&challenge, 0);
+    program_test.add_account(
+        find_challenge_account(&info_address, &program_id).0,
+        Account {
+            lamports: LAMPORTS,
+            data,
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::resolve_challenge(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &mint,
+        &challenger,
+        &FEE_RECEIVER,
+        false,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "ResolveChallenge",
+        consumed,
+        compute_budget::RESOLVE_CHALLENGE,
+    );
+}
+
+#[tokio::test]
+async fn set_listing_bond_lock_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_listing_bond_lock(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        DEFAULT_LISTING_BOND_LOCK,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "SetListingBondLock",
+        consumed,
+        compute_budget::SET_LISTING_BOND_LOCK,
+    );
+}
+
+#[tokio::test]
+async fn post_listing_bond_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        find_info_account(&GLOBAL_TENANT, &mint, &program_id).0,
+        info_account(&program_id, &mint, &creator),
+    );
+    program_test.add_account(creator, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction =
+        client::post_listing_bond(&program_id, &GLOBAL_TENANT, &creator, &mint, FEE_AMOUNT);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "PostListingBond",
+        consumed,
+        compute_budget::POST_LISTING_BOND,
+    );
+}
+
+#[tokio::test]
+async fn reclaim_bond_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let info_address = find_info_account(&GLOBAL_TENANT, &mint, &program_id).0;
+    program_test.add_account(info_address, info_account(&program_id, &mint, &creator));
+    program_test.add_account(
+        find_listing_bond_account(&info_address, &program_id).0,
+        listing_bond_account(&program_id, &creator, &mint),
+    );
+    program_test.add_account(creator, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::reclaim_bond(&program_id, &GLOBAL_TENANT, &creator, &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("ReclaimBond", consumed, compute_budget::RECLAIM_BOND);
+}
+
+#[tokio::test]
+async fn forfeit_bond_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let info_address = find_info_account(&GLOBAL_TENANT, &mint, &program_id).0;
+    program_test.add_account(
+        find_listing_bond_account(&info_address, &program_id).0,
+        listing_bond_account(&program_id, &creator, &mint),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::forfeit_bond(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &mint,
+        &creator,
+        &FEE_RECEIVER,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("ForfeitBond", consumed, compute_budget::FORFEIT_BOND);
+}
+
+#[tokio::test]
+async fn create_info_v2_through_operator_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    let origin = Pubkey::new_unique();
+    let payout = Pubkey::new_unique();
+    program_test.add_account(
+        find_operator_account(&GLOBAL_TENANT, &origin, &program_id).0,
+        operator_account(&program_id, &origin, &payout),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let args = CreateInfoArgs {
+        origin: Some(origin),
+        ..sample_args("A token launched through an operator")
+    };
+    let instruction = Instruction::new_with_borsh(
+        program_id,
+        &launch_lock::Instruction::CreateInfoV2(args),
+        CreateInfoAccounts {
+            tenant: GLOBAL_TENANT,
+            payer: payer.pubkey(),
+            creator: payer.pubkey(),
+            mint,
+            authority: AUTHORITY,
+            fee_receiver: FEE_RECEIVER,
+            prepaid: false,
+            price_update: None,
+            origin: Some(origin),
+            operator_payout: Some(payout),
+        }
+        .to_account_metas(&program_id),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget(
+        "CreateInfoV2 (operator)",
+        consumed,
+        compute_budget::CREATE_INFO_V2,
+    );
+}
+
+#[tokio::test]
+async fn set_operator_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    program_test.add_account(AUTHORITY, Account::new(LAMPORTS, 0, &system_program::id()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::set_operator(
+        &program_id,
+        &GLOBAL_TENANT,
+        &AUTHORITY,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        2_000,
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("SetOperator", consumed, compute_budget::SET_OPERATOR);
+}
+
+#[tokio::test]
+async fn remove_operator_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let operator = Pubkey::new_unique();
+    program_test.add_account(
+        find_operator_account(&GLOBAL_TENANT, &operator, &program_id).0,
+        operator_account(&program_id, &operator, &Pubkey::new_unique()),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::remove_operator(&program_id, &GLOBAL_TENANT, &AUTHORITY, &operator);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("RemoveOperator", consumed, compute_budget::REMOVE_OPERATOR);
+}
+
+#[tokio::test]
+async fn validate_config_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::validate_config(
+        &program_id,
+        &GLOBAL_TENANT,
+        Some(AdminAction::SetAdminDelay {
+            delay: 2 * MAX_CONFIG_DELAY,
+        }),
+    );
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("ValidateConfig", consumed, compute_budget::VALIDATE_CONFIG);
+}
+
+#[tokio::test]
+async fn get_stats_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    program_test.add_account(
+        find_config_account(&GLOBAL_TENANT, &program_id).0,
+        config_account(&program_id),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::get_stats(&program_id, &GLOBAL_TENANT);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("GetStats", consumed, compute_budget::GET_STATS);
+}
+
+#[tokio::test]
+async fn get_mint_stats_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let info_address = find_info_account(&GLOBAL_TENANT, &mint, &program_id).0;
+    program_test.add_account(info_address, info_account(&program_id, &mint, &creator));
+    program_test.add_account(
+        find_listing_bond_account(&info_address, &program_id).0,
+        listing_bond_account(&program_id, &creator, &mint),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::get_mint_stats(&program_id, &GLOBAL_TENANT, &mint);
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("GetMintStats", consumed, compute_budget::GET_MINT_STATS);
+}
+
+#[tokio::test]
+async fn get_api_version_within_budget() {
+    let program_id = Pubkey::new_unique();
+    let program_test = program_test(program_id);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instruction = client::versioned(PROGRAM_API_VERSION, client::get_api_version(&program_id));
+    let consumed = units_consumed(
+        &mut banks_client,
+        &payer.pubkey(),
+        instruction,
+        recent_blockhash,
+    )
+    .await;
+    assert_within_budget("GetApiVersion", consumed, compute_budget::GET_API_VERSION);
+}
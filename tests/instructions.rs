@@ -0,0 +1,1923 @@
+//! What the instructions do, as opposed to what they cost: the errors they
+//! return and the accounts they leave behind. `cargo test --features bench`
+//! runs them on the native processor.
+#![cfg(feature = "bench")]
+
+mod common;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use borsh::BorshDeserialize;
+use common::*;
+use launch_lock::{
+    AUTHORITY, AccountHeader, AdminAction, AllowList, Config, ConfigViolation, CreateInfoArgs,
+    CreatedInfo, CreationQuota, DEFAULT_MAX_ACCOUNT_SIZE, DescriptionFormat, FEE_AMOUNT,
+    FEE_RECEIVER, FeeStats, GLOBAL_TENANT, Instruction as ProgramInstruction, LaunchTimeline,
+    ListingBond, LoyaltyTier, MintStats, Operator, PROGRAM_API_VERSION, Receipt, Stats, TokenInfo,
+    TokenInfoError, TokenInfoV2,
+    accounts::CreateInfoAccounts,
+    client,
+    events::{self, Event},
+    find_blacklist_account, find_challenge_account, find_config_account,
+    find_creation_quota_account, find_fee_stats_account, find_info_account,
+    find_listing_bond_account, find_operator_account, find_pending_action_account,
+    find_receipt_account, find_sponsorship_vault, token,
+};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    hash::hash,
+    instruction::{Instruction, InstructionError},
+    message::Message,
+    program_stubs::{SyscallStubs, set_syscall_stubs},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+    transaction_context::TransactionReturnData,
+};
+use std::sync::Once;
+
+/// Native program-test prints `sol_log_data` to stdout rather than to the
+/// transaction log. This forwards it to `sol_log` instead, so events can be
+/// decoded from the log natively as they are on SBF.
+struct LogDataStubs(Box<dyn SyscallStubs>);
+
+impl SyscallStubs for LogDataStubs {
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        let fields: Vec<String> = fields.iter().map(|field| STANDARD.encode(field)).collect();
+        self.0
+            .sol_log(&format!("{NATIVE_DATA_PREFIX}{}", fields.join(" ")));
+    }
+
+    fn sol_log(&self, message: &str) {
+        self.0.sol_log(message)
+    }
+    fn sol_log_compute_units(&self) {
+        self.0.sol_log_compute_units()
+    }
+    fn sol_remaining_compute_units(&self) -> u64 {
+        self.0.sol_remaining_compute_units()
+    }
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        self.0
+            .sol_invoke_signed(instruction, account_infos, signers_seeds)
+    }
+    fn sol_get_sysvar(
+        &self,
+        sysvar_id_addr: *const u8,
+        var_addr: *mut u8,
+        offset: u64,
+        length: u64,
+    ) -> u64 {
+        self.0
+            .sol_get_sysvar(sysvar_id_addr, var_addr, offset, length)
+    }
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_clock_sysvar(var_addr)
+    }
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_epoch_schedule_sysvar(var_addr)
+    }
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_fees_sysvar(var_addr)
+    }
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_rent_sysvar(var_addr)
+    }
+    fn sol_get_epoch_rewards_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_epoch_rewards_sysvar(var_addr)
+    }
+    fn sol_get_last_restart_slot(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_last_restart_slot(var_addr)
+    }
+    fn sol_get_epoch_stake(&self, vote_address: *const u8) -> u64 {
+        self.0.sol_get_epoch_stake(vote_address)
+    }
+    unsafe fn sol_memcpy(&self, dst: *mut u8, src: *const u8, n: usize) {
+        unsafe { self.0.sol_memcpy(dst, src, n) }
+    }
+    unsafe fn sol_memmove(&self, dst: *mut u8, src: *const u8, n: usize) {
+        unsafe { self.0.sol_memmove(dst, src, n) }
+    }
+    unsafe fn sol_memcmp(&self, s1: *const u8, s2: *const u8, n: usize, result: *mut i32) {
+        unsafe { self.0.sol_memcmp(s1, s2, n, result) }
+    }
+    unsafe fn sol_memset(&self, s: *mut u8, c: u8, n: usize) {
+        unsafe { self.0.sol_memset(s, c, n) }
+    }
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        self.0.sol_get_return_data()
+    }
+    fn sol_set_return_data(&self, data: &[u8]) {
+        self.0.sol_set_return_data(data)
+    }
+    fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+        self.0.sol_get_processed_sibling_instruction(index)
+    }
+    fn sol_get_stack_height(&self) -> u64 {
+        self.0.sol_get_stack_height()
+    }
+}
+
+/// How `LogDataStubs` events show up in the log; `sol_log` adds its own
+/// `Program log: ` in front.
+const NATIVE_DATA_PREFIX: &str = "Program data: ";
+
+struct NoStubs;
+
+impl SyscallStubs for NoStubs {}
+
+/// Wraps program-test's stubs in `LogDataStubs`. Program-test installs them
+/// when the first bank starts, so a throwaway one is started first; every
+/// test passes through here before starting its own.
+fn log_events_natively() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        std::thread::spawn(|| {
+            tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(ProgramTest::default().start());
+        })
+        .join()
+        .unwrap();
+        let stubs = set_syscall_stubs(Box::new(NoStubs));
+        set_syscall_stubs(Box::new(LogDataStubs(stubs)));
+    });
+}
+
+/// The program with a global config administered by `admin`, who also
+/// co-signs creates, so tests can sign for both. `admin` is funded for
+/// config reallocations.
+fn program_with_config(
+    admin: &Pubkey,
+    configure: impl FnOnce(&mut Config),
+) -> (ProgramTest, Pubkey) {
+    log_events_natively();
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let mut config = Config {
+        admin: *admin,
+        authority: Some(*admin),
+        ..sample_config()
+    };
+    configure(&mut config);
+    program_test.add_account(*admin, system_account(LAMPORTS));
+    program_test.add_account(
+        config_address(&program_id),
+        config_account_with(&program_id, config),
+    );
+    (program_test, program_id)
+}
+
+/// An spl-token mint with 9 decimals.
+fn add_mint(program_test: &mut ProgramTest) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account());
+    mint
+}
+
+/// An existing record for a fresh mint, created by `creator`.
+fn add_info(program_test: &mut ProgramTest, program_id: &Pubkey, creator: &Pubkey) -> Pubkey {
+    let mint = add_mint(program_test);
+    program_test.add_account(
+        info_address(program_id, &mint),
+        info_account(program_id, &mint, creator),
+    );
+    mint
+}
+
+fn config_address(program_id: &Pubkey) -> Pubkey {
+    find_config_account(&GLOBAL_TENANT, program_id).0
+}
+
+fn info_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    find_info_account(&GLOBAL_TENANT, mint, program_id).0
+}
+
+/// Signs with the context payer and `signers` over a fresh blockhash, so
+/// resending a failed transaction runs it again instead of returning the
+/// cached error.
+async fn sign(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Transaction {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    )
+}
+
+/// Returns the transaction error, if any.
+async fn send(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), TransactionError> {
+    let transaction = sign(context, instructions, signers).await;
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+/// Simulates an unsigned transaction, for instructions that need a signer
+/// the tests don't hold, such as `AUTHORITY`. Nothing is committed.
+async fn simulate(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+) -> Result<(), TransactionError> {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let message =
+        Message::new_with_blockhash(instructions, Some(&context.payer.pubkey()), &blockhash);
+    let simulation = context
+        .banks_client
+        .simulate_transaction(Transaction::new_unsigned(message))
+        .await
+        .unwrap();
+    simulation.result.expect("the transaction ran")
+}
+
+/// What a successful transaction left behind besides account changes.
+struct Processed {
+    events: Vec<Event>,
+    return_data: Option<TransactionReturnData>,
+}
+
+/// Sends a transaction that must succeed and decodes the events it logged.
+async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Processed {
+    let transaction = sign(context, instructions, signers).await;
+    let processed = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    processed.result.unwrap();
+    let metadata = processed.metadata.unwrap();
+    let events = metadata
+        .log_messages
+        .iter()
+        .filter_map(|log| {
+            log.strip_prefix("Program data: ").or_else(|| {
+                log.strip_prefix("Program log: ")?
+                    .strip_prefix(NATIVE_DATA_PREFIX)
+            })
+        })
+        .map(|data| Event::try_from_slice(&STANDARD.decode(data).unwrap()).unwrap())
+        .collect();
+    Processed {
+        events,
+        return_data: metadata.return_data,
+    }
+}
+
+/// The error a program failure in instruction `index` surfaces as. Only the
+/// variant reaches the transaction; its fields are in the log.
+fn program_error(index: u8, error: TokenInfoError) -> TransactionError {
+    TransactionError::InstructionError(index, InstructionError::Custom(error.code()))
+}
+
+async fn account(context: &mut ProgramTestContext, address: &Pubkey) -> Option<Account> {
+    context.banks_client.get_account(*address).await.unwrap()
+}
+
+/// Decodes the body of a program account, ignoring the header and any
+/// trailing headroom.
+async fn record<T: BorshDeserialize>(context: &mut ProgramTestContext, address: &Pubkey) -> T {
+    let account = account(context, address).await.expect("account exists");
+    T::deserialize(&mut &account.data[AccountHeader::LEN..]).unwrap()
+}
+
+async fn info_record(context: &mut ProgramTestContext, address: &Pubkey) -> TokenInfoV2 {
+    match record(context, address).await {
+        TokenInfo::V2(info) => info,
+        TokenInfo::V1(_) => panic!("expected a V2 record"),
+    }
+}
+
+/// Moves the clock's unix timestamp forward; slots are left alone.
+async fn advance_clock(context: &mut ProgramTestContext, seconds: i64) {
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += seconds;
+    context.set_sysvar(&clock);
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp
+}
+
+async fn balance(context: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    context.banks_client.get_balance(*address).await.unwrap()
+}
+
+fn create_info_v2(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+    args: CreateInfoArgs,
+) -> Instruction {
+    client::create_info_v2(
+        program_id,
+        &GLOBAL_TENANT,
+        payer,
+        payer,
+        mint,
+        &FEE_RECEIVER,
+        admin,
+        args,
+    )
+}
+
+#[tokio::test]
+async fn create_info_v2_writes_the_record_and_charges_the_fee() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    send(&mut context, &[instruction], &[&admin]).await.unwrap();
+
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.mint, mint);
+    assert_eq!(info.creator, payer);
+    assert_eq!(info.description, "A token");
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, FEE_AMOUNT);
+}
+
+fn system_account(lamports: u64) -> Account {
+    Account::new(lamports, 0, &solana_sdk::system_program::id())
+}
+
+fn create_info_sponsored(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    client::create_info_sponsored(
+        program_id,
+        &GLOBAL_TENANT,
+        creator,
+        mint,
+        &FEE_RECEIVER,
+        admin,
+        sample_args("A sponsored token"),
+    )
+}
+
+#[tokio::test]
+async fn create_info_sponsored_charges_the_vault_within_the_cap() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let vault = find_sponsorship_vault(&GLOBAL_TENANT, &program_id).0;
+    program_test.add_account(vault, system_account(LAMPORTS));
+    let mut context = program_test.start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let instruction = create_info_sponsored(&program_id, &creator, &admin.pubkey(), &mint);
+    send(&mut context, &[instruction], &[&admin]).await.unwrap();
+
+    let info_account = info_address(&program_id, &mint);
+    let info = info_record(&mut context, &info_account).await;
+    assert_eq!(info.creator, creator);
+    assert_eq!(info.payer, vault);
+    let rent = balance(&mut context, &info_account).await;
+    let spent = LAMPORTS - balance(&mut context, &vault).await;
+    assert!(spent >= FEE_AMOUNT + rent);
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.sponsorship_spent_today, spent);
+    assert_eq!(config.sponsored_creates, 1);
+    assert_eq!(config.total_creates, 1);
+}
+
+#[tokio::test]
+async fn create_info_sponsored_stops_at_the_daily_cap() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.sponsorship_daily_cap = FEE_AMOUNT;
+    });
+    let mint = add_mint(&mut program_test);
+    let vault = find_sponsorship_vault(&GLOBAL_TENANT, &program_id).0;
+    program_test.add_account(vault, system_account(LAMPORTS));
+    let mut context = program_test.start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let instruction = create_info_sponsored(&program_id, &creator, &admin.pubkey(), &mint);
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::SponsorshipCapExceeded {
+                cap: FEE_AMOUNT,
+                spent: 0,
+                requested: FEE_AMOUNT,
+            }
+        )
+    );
+    let info_account = info_address(&program_id, &mint);
+    assert!(account(&mut context, &info_account).await.is_none());
+    assert_eq!(balance(&mut context, &vault).await, LAMPORTS);
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.sponsorship_spent_today, 0);
+    assert_eq!(config.sponsored_creates, 0);
+}
+
+#[tokio::test]
+async fn create_info_v2_refuses_records_over_the_max_account_size() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.max_account_size = 256;
+    });
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::AccountTooLarge { size: 0, max: 256 })
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, 0);
+}
+
+#[tokio::test]
+async fn set_max_account_size_lets_the_same_record_through() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.max_account_size = 256;
+    });
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let set_size =
+        client::set_max_account_size(&program_id, &GLOBAL_TENANT, &admin.pubkey(), 4_096);
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    send(&mut context, &[set_size, instruction], &[&admin])
+        .await
+        .unwrap();
+
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.max_account_size, 4_096);
+    let info_account = account(&mut context, &info_address(&program_id, &mint))
+        .await
+        .unwrap();
+    assert!(info_account.data.len() <= 4_096);
+}
+
+#[tokio::test]
+async fn create_info_v2_honors_the_allowed_description_formats() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.allowed_description_formats = DescriptionFormat::PlainText.mask();
+    });
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let markdown = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("*A token*"),
+    );
+    let err = send(&mut context, std::slice::from_ref(&markdown), &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::DescriptionFormatNotAllowed)
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+
+    let allow = client::set_allowed_description_formats(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        vec![DescriptionFormat::PlainText, DescriptionFormat::Markdown],
+    );
+    send(&mut context, &[allow, markdown], &[&admin])
+        .await
+        .unwrap();
+
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.allowed_description_formats, DescriptionFormat::ALL);
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.description_format, DescriptionFormat::Markdown);
+    assert_eq!(info.description, "*A token*");
+}
+
+#[tokio::test]
+async fn create_info_v2_checks_ipfs_cids() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let mut args = sample_args("A token");
+    args.icon_uri = "ipfs://not-a-cid".to_string();
+    let instruction = create_info_v2(&program_id, &payer, &admin.pubkey(), &mint, args);
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::InvalidIpfsCid));
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+
+    let icon_uri = "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG/icon.png";
+    let mut args = sample_args("A token");
+    args.icon_uri = icon_uri.to_string();
+    let instruction = create_info_v2(&program_id, &payer, &admin.pubkey(), &mint, args);
+    send(&mut context, &[instruction], &[&admin]).await.unwrap();
+
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.images.icon, icon_uri);
+}
+
+#[tokio::test]
+async fn create_info_v2_snapshots_the_mint() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = Pubkey::new_unique();
+    let mut mint_data = mint_account();
+    mint_data.data[36..44].copy_from_slice(&42_000u64.to_le_bytes());
+    mint_data.data[44] = 6;
+    program_test.add_account(mint, mint_data);
+    let uninitialized = Pubkey::new_unique();
+    let mut uninitialized_data = mint_account();
+    uninitialized_data.data[45] = 0;
+    program_test.add_account(uninitialized, uninitialized_data);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &uninitialized,
+        sample_args("A token"),
+    );
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::InvalidMint));
+    assert!(
+        account(&mut context, &info_address(&program_id, &uninitialized))
+            .await
+            .is_none()
+    );
+
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    send(&mut context, &[instruction], &[&admin]).await.unwrap();
+
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.mint_decimals, 6);
+    assert_eq!(info.mint_supply_at_creation, 42_000);
+}
+
+#[tokio::test]
+async fn update_timeline_rejects_a_presale_ending_before_it_starts() {
+    let admin = Keypair::new();
+    let creator = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    program_test.add_account(creator.pubkey(), system_account(LAMPORTS));
+    let mint = add_info(&mut program_test, &program_id, &creator.pubkey());
+    let mut context = program_test.start_with_context().await;
+
+    let backwards = LaunchTimeline {
+        presale_start: Some(2_000),
+        presale_end: Some(1_000),
+        ..LaunchTimeline::default()
+    };
+    let instruction = client::update_timeline(
+        &program_id,
+        &GLOBAL_TENANT,
+        &creator.pubkey(),
+        &mint,
+        backwards,
+    );
+    let err = send(&mut context, &[instruction], &[&creator])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::InvalidTimeline));
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.timeline, LaunchTimeline::default());
+
+    let timeline = LaunchTimeline {
+        launch_ts: Some(3_000),
+        presale_start: Some(1_000),
+        presale_end: Some(2_000),
+        listing_ts: None,
+    };
+    let instruction = client::update_timeline(
+        &program_id,
+        &GLOBAL_TENANT,
+        &creator.pubkey(),
+        &mint,
+        timeline,
+    );
+    send(&mut context, &[instruction], &[&creator])
+        .await
+        .unwrap();
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.timeline, timeline);
+}
+
+#[tokio::test]
+async fn create_info_v2_honors_the_allow_lists() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.allowed_mints = vec![Pubkey::new_unique()];
+    });
+    let mint = add_mint(&mut program_test);
+    let token_2022_mint = Pubkey::new_unique();
+    program_test.add_account(
+        token_2022_mint,
+        Account {
+            owner: token::TOKEN_2022_PROGRAM_ID,
+            ..mint_account()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &token_2022_mint,
+        sample_args("A token"),
+    );
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::TokenProgramNotAllowed)
+    );
+
+    let create = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    let err = send(&mut context, std::slice::from_ref(&create), &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::MintNotAllowed));
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+
+    let allow = client::add_to_allow_list(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        AllowList::Mints,
+        mint,
+    );
+    send(&mut context, &[allow, create], &[&admin])
+        .await
+        .unwrap();
+
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert!(config.allowed_mints.contains(&mint));
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.mint, mint);
+}
+
+#[tokio::test]
+async fn create_info_v2_refuses_blacklisted_mints() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let blacklist = client::add_to_blacklist(&program_id, &GLOBAL_TENANT, &admin.pubkey(), &mint);
+    send(&mut context, &[blacklist], &[&admin]).await.unwrap();
+    let entry = find_blacklist_account(&GLOBAL_TENANT, &mint, &program_id).0;
+    assert!(account(&mut context, &entry).await.is_some());
+
+    let create = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    let err = send(&mut context, std::slice::from_ref(&create), &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::MintBlacklisted));
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, 0);
+
+    let unblacklist =
+        client::remove_from_blacklist(&program_id, &GLOBAL_TENANT, &admin.pubkey(), &mint);
+    send(&mut context, &[unblacklist, create], &[&admin])
+        .await
+        .unwrap();
+    assert!(account(&mut context, &entry).await.is_none());
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.mint, mint);
+}
+
+#[tokio::test]
+async fn accept_fee_receiver_waits_out_the_delay() {
+    let admin = Keypair::new();
+    let candidate = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.fee_receiver_delay = 3_600;
+    });
+    program_test.add_account(candidate.pubkey(), system_account(LAMPORTS));
+    let mut context = program_test.start_with_context().await;
+
+    let propose = client::propose_fee_receiver(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &candidate.pubkey(),
+    );
+    send(&mut context, &[propose], &[&admin]).await.unwrap();
+
+    let accept = client::accept_fee_receiver(&program_id, &GLOBAL_TENANT, &candidate.pubkey());
+    let err = send(&mut context, std::slice::from_ref(&accept), &[&candidate])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::TimelockNotElapsed {
+                executable_at: 0,
+                now: 0,
+            }
+        )
+    );
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.fee_receiver, FEE_RECEIVER);
+    assert_eq!(
+        config.pending_fee_receiver.map(|pending| pending.candidate),
+        Some(candidate.pubkey())
+    );
+
+    advance_clock(&mut context, 3_600).await;
+    send(&mut context, &[accept], &[&candidate]).await.unwrap();
+
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.fee_receiver, candidate.pubkey());
+    assert!(config.pending_fee_receiver.is_none());
+}
+
+#[tokio::test]
+async fn admin_actions_wait_out_the_admin_delay() {
+    let admin = Keypair::new();
+    let (program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.admin_delay = 3_600;
+    });
+    let mut context = program_test.start_with_context().await;
+    let executor = context.payer.pubkey();
+
+    let direct = client::set_max_account_size(&program_id, &GLOBAL_TENANT, &admin.pubkey(), 4_096);
+    let err = send(&mut context, &[direct], &[&admin]).await.unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::TimelockRequired));
+
+    let queue = client::queue_admin_action(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        0,
+        AdminAction::SetMaxAccountSize {
+            max_account_size: 4_096,
+        },
+    );
+    send(&mut context, &[queue], &[&admin]).await.unwrap();
+    let pending_action = find_pending_action_account(&GLOBAL_TENANT, 0, &program_id).0;
+    assert!(account(&mut context, &pending_action).await.is_some());
+
+    let execute =
+        client::execute_admin_action(&program_id, &GLOBAL_TENANT, &executor, 0, &admin.pubkey());
+    let err = send(&mut context, std::slice::from_ref(&execute), &[])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::TimelockNotElapsed {
+                executable_at: 0,
+                now: 0,
+            }
+        )
+    );
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.max_account_size, DEFAULT_MAX_ACCOUNT_SIZE);
+    assert_eq!(config.next_action_id, 1);
+
+    advance_clock(&mut context, 3_600).await;
+    send(&mut context, &[execute], &[]).await.unwrap();
+
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.max_account_size, 4_096);
+    assert!(account(&mut context, &pending_action).await.is_none());
+}
+
+#[tokio::test]
+async fn paid_creates_accumulate_in_the_payer_fee_stats() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let first = add_mint(&mut program_test);
+    let second = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    for mint in [first, second] {
+        let instruction = create_info_v2(
+            &program_id,
+            &payer,
+            &admin.pubkey(),
+            &mint,
+            sample_args("A token"),
+        );
+        send(&mut context, &[instruction], &[&admin]).await.unwrap();
+    }
+
+    let stats: FeeStats = record(
+        &mut context,
+        &find_fee_stats_account(&GLOBAL_TENANT, &payer, &program_id).0,
+    )
+    .await;
+    assert_eq!(stats.payer, payer);
+    assert_eq!(stats.paid_creations, 2);
+    assert_eq!(
+        stats.total_fees_paid,
+        balance(&mut context, &FEE_RECEIVER).await
+    );
+    assert!(stats.first_paid_at <= stats.last_paid_at);
+}
+
+#[tokio::test]
+async fn info_updated_hashes_the_record_without_its_header() {
+    let admin = Keypair::new();
+    let creator = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    program_test.add_account(creator.pubkey(), system_account(LAMPORTS));
+    let mint = add_info(&mut program_test, &program_id, &creator.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let info_account = info_address(&program_id, &mint);
+    let before = info_record(&mut context, &info_account).await;
+    let raw_before = account(&mut context, &info_account).await.unwrap().data;
+
+    let timeline = LaunchTimeline {
+        launch_ts: Some(3_000),
+        ..LaunchTimeline::default()
+    };
+    let instruction = client::update_timeline(
+        &program_id,
+        &GLOBAL_TENANT,
+        &creator.pubkey(),
+        &mint,
+        timeline,
+    );
+    let logged = process(&mut context, &[instruction], &[&creator])
+        .await
+        .events;
+
+    let after = info_record(&mut context, &info_account).await;
+    let [
+        Event::InfoUpdated {
+            mint: updated_mint,
+            changed_fields,
+            old_hash,
+            new_hash,
+        },
+    ] = logged.as_slice()
+    else {
+        panic!("expected one InfoUpdated event, got {logged:?}");
+    };
+    assert_eq!(*updated_mint, mint);
+    assert_eq!(*changed_fields, events::CHANGED_TIMELINE);
+    let body_hash =
+        |info: TokenInfoV2| hash(&borsh::to_vec(&TokenInfo::V2(info)).unwrap()).to_bytes();
+    assert_eq!(*old_hash, body_hash(before));
+    assert_eq!(*new_hash, body_hash(after));
+    assert_ne!(*old_hash, hash(&raw_before).to_bytes());
+}
+
+#[tokio::test]
+async fn creates_stop_at_the_creator_daily_quota() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.creator_daily_quota = 1;
+    });
+    let first = add_mint(&mut program_test);
+    let second = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let instruction = create_info_v2(
+        &program_id,
+        &creator,
+        &admin.pubkey(),
+        &first,
+        sample_args("A token"),
+    );
+    send(&mut context, &[instruction], &[&admin]).await.unwrap();
+    let instruction = create_info_v2(
+        &program_id,
+        &creator,
+        &admin.pubkey(),
+        &second,
+        sample_args("A token"),
+    );
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::QuotaExceeded { quota: 1 })
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &second))
+            .await
+            .is_none()
+    );
+    let quota: CreationQuota = record(
+        &mut context,
+        &find_creation_quota_account(&GLOBAL_TENANT, &creator, &program_id).0,
+    )
+    .await;
+    assert_eq!(quota.wallet, creator);
+    assert_eq!(quota.created, 1);
+}
+
+#[tokio::test]
+async fn fixed_size_instructions_refuse_extra_bytes() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_info(&mut program_test, &program_id, &Pubkey::new_unique());
+    let mut context = program_test.start_with_context().await;
+    let endorser = context.payer.pubkey();
+
+    // Endorse carries no arguments: its tag is all there is.
+    let instruction = client::endorse(&program_id, &GLOBAL_TENANT, &endorser, &mint);
+    let mut padded = instruction.clone();
+    padded.data.push(0);
+    let err = send(&mut context, &[padded], &[]).await.unwrap_err();
+
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::InstructionTooLarge { len: 2, max: 1 })
+    );
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.endorsements, 0);
+
+    send(&mut context, &[instruction], &[]).await.unwrap();
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.endorsements, 1);
+}
+
+#[tokio::test]
+async fn create_info_v2_refuses_trailing_bytes() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let mut instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    instruction.data.extend_from_slice(&[0; 4]);
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, 0);
+}
+
+#[tokio::test]
+async fn creates_report_the_canonical_info_account() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    let processed = process(&mut context, &[instruction], &[&admin]).await;
+
+    let (info_account, bump) = find_info_account(&GLOBAL_TENANT, &mint, &program_id);
+    let return_data = processed.return_data.expect("create returns data");
+    assert_eq!(return_data.program_id, program_id);
+    assert_eq!(
+        CreatedInfo::try_from_slice(&return_data.data).unwrap(),
+        CreatedInfo { info_account, bump }
+    );
+    let created: Vec<_> = processed
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            Event::InfoCreated {
+                tenant,
+                mint,
+                creator,
+                info_account,
+                bump,
+            } => Some((*tenant, *mint, *creator, *info_account, *bump)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(created, [(GLOBAL_TENANT, mint, payer, info_account, bump)]);
+}
+
+#[tokio::test]
+async fn create_info_prepaid_needs_the_whole_fee_transferred_first() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let create = client::create_info_prepaid(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer,
+        &payer,
+        &mint,
+        &FEE_RECEIVER,
+        &admin.pubkey(),
+        sample_args("A token"),
+    );
+    let err = send(&mut context, std::slice::from_ref(&create), &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::FeeNotPrepaid { fee: FEE_AMOUNT })
+    );
+
+    let short = system_instruction::transfer(&payer, &FEE_RECEIVER, FEE_AMOUNT - 1);
+    let err = send(&mut context, &[short, create.clone()], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(1, TokenInfoError::FeeNotPrepaid { fee: FEE_AMOUNT })
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, 0);
+
+    let payment = system_instruction::transfer(&payer, &FEE_RECEIVER, FEE_AMOUNT);
+    send(&mut context, &[payment, create], &[&admin])
+        .await
+        .unwrap();
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.payer, payer);
+    // The transfer paid the fee; the create didn't charge it again.
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, FEE_AMOUNT);
+}
+
+#[tokio::test]
+async fn refund_receipt_pays_back_once() {
+    let admin = Keypair::new();
+    let fee_receiver = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.fee_receiver = fee_receiver.pubkey();
+    });
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let create = client::create_info_v2(
+        &program_id,
+        &GLOBAL_TENANT,
+        &payer,
+        &payer,
+        &mint,
+        &fee_receiver.pubkey(),
+        &admin.pubkey(),
+        sample_args("A token"),
+    );
+    send(&mut context, &[create], &[&admin]).await.unwrap();
+    let receipt_account = find_receipt_account(&GLOBAL_TENANT, &payer, &mint, &program_id).0;
+    let receipt: Receipt = record(&mut context, &receipt_account).await;
+    assert_eq!(receipt.payer, payer);
+    assert_eq!(receipt.mint, mint);
+    assert_eq!(receipt.info_account, info_address(&program_id, &mint));
+    assert_eq!(receipt.fee, FEE_AMOUNT);
+    assert_eq!(receipt.refunded, 0);
+
+    let refund = client::refund_receipt(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &fee_receiver.pubkey(),
+        &payer,
+        &mint,
+    );
+    send(
+        &mut context,
+        std::slice::from_ref(&refund),
+        &[&admin, &fee_receiver],
+    )
+    .await
+    .unwrap();
+    let receipt: Receipt = record(&mut context, &receipt_account).await;
+    assert_eq!(receipt.refunded, FEE_AMOUNT);
+    assert_eq!(balance(&mut context, &fee_receiver.pubkey()).await, 0);
+
+    let err = send(&mut context, &[refund], &[&admin, &fee_receiver])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::AlreadyRefunded));
+    let receipt: Receipt = record(&mut context, &receipt_account).await;
+    assert_eq!(receipt.refunded, FEE_AMOUNT);
+}
+
+#[tokio::test]
+async fn refund_fee_refuses_zero_and_keeps_the_receipt_refundable() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let payer = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let receipt = find_receipt_account(&GLOBAL_TENANT, &payer, &mint, &program_id).0;
+    program_test.add_account(receipt, receipt_account(&program_id, &payer, &mint));
+    let vault = find_sponsorship_vault(&GLOBAL_TENANT, &program_id).0;
+    program_test.add_account(vault, system_account(LAMPORTS));
+    let mut context = program_test.start_with_context().await;
+    let refund_fee = |amount| {
+        client::refund_fee(
+            &program_id,
+            &GLOBAL_TENANT,
+            &admin.pubkey(),
+            &payer,
+            &mint,
+            amount,
+        )
+    };
+
+    let err = send(&mut context, &[refund_fee(0)], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+    let err = send(&mut context, &[refund_fee(FEE_AMOUNT + 1)], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::RefundExceedsFee {
+                amount: FEE_AMOUNT + 1,
+                fee: FEE_AMOUNT,
+            }
+        )
+    );
+    let stored: Receipt = record(&mut context, &receipt).await;
+    assert_eq!(stored.refunded, 0);
+    assert_eq!(balance(&mut context, &vault).await, LAMPORTS);
+
+    send(&mut context, &[refund_fee(FEE_AMOUNT / 2)], &[&admin])
+        .await
+        .unwrap();
+    let stored: Receipt = record(&mut context, &receipt).await;
+    assert_eq!(stored.refunded, FEE_AMOUNT / 2);
+    assert_eq!(balance(&mut context, &payer).await, FEE_AMOUNT / 2);
+    assert_eq!(
+        balance(&mut context, &vault).await,
+        LAMPORTS - FEE_AMOUNT / 2
+    );
+
+    let err = send(&mut context, &[refund_fee(FEE_AMOUNT / 2)], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::AlreadyRefunded));
+}
+
+#[tokio::test]
+async fn set_verified_batch_leaves_challenged_records_unverified() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.verification_dispute_delay = 3_600;
+    });
+    let challenged = add_info(&mut program_test, &program_id, &Pubkey::new_unique());
+    let unchallenged = add_info(&mut program_test, &program_id, &Pubkey::new_unique());
+    let mut context = program_test.start_with_context().await;
+    let challenger = context.payer.pubkey();
+    let info_accounts = [
+        info_address(&program_id, &challenged),
+        info_address(&program_id, &unchallenged),
+    ];
+
+    let verify = client::set_verified_batch(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &info_accounts,
+        vec![true, true],
+    );
+    send(&mut context, &[verify], &[&admin]).await.unwrap();
+    let info = info_record(&mut context, &info_accounts[0]).await;
+    assert!(info.verified);
+    assert!(!info.is_verified(now(&mut context).await));
+
+    let challenge =
+        client::challenge_verification(&program_id, &GLOBAL_TENANT, &challenger, &challenged);
+    send(&mut context, &[challenge], &[]).await.unwrap();
+    let challenge_account = find_challenge_account(&info_accounts[0], &program_id).0;
+    assert!(account(&mut context, &challenge_account).await.is_some());
+
+    // The admin can't settle a challenge through the batch, in either direction.
+    for flag in [false, true] {
+        let batch = client::set_verified_batch(
+            &program_id,
+            &GLOBAL_TENANT,
+            &admin.pubkey(),
+            &info_accounts[..1],
+            vec![flag],
+        );
+        send(&mut context, &[batch], &[&admin]).await.unwrap();
+        let after = info_record(&mut context, &info_accounts[0]).await;
+        assert!(after.challenged);
+        assert!(after.verified);
+        assert_eq!(after.verified_at, info.verified_at);
+    }
+
+    advance_clock(&mut context, 3_600).await;
+    let now = now(&mut context).await;
+    let challenged_info = info_record(&mut context, &info_accounts[0]).await;
+    assert!(!challenged_info.is_verified(now));
+    let unchallenged_info = info_record(&mut context, &info_accounts[1]).await;
+    assert!(unchallenged_info.is_verified(now));
+}
+
+#[tokio::test]
+async fn reclaim_bond_waits_for_the_lock_and_releases_once() {
+    let admin = Keypair::new();
+    let creator = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.listing_bond_lock = 3_600;
+    });
+    program_test.add_account(creator.pubkey(), system_account(LAMPORTS));
+    let mint = add_info(&mut program_test, &program_id, &creator.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let bond_account = find_listing_bond_account(&info_address(&program_id, &mint), &program_id).0;
+
+    let post = client::post_listing_bond(
+        &program_id,
+        &GLOBAL_TENANT,
+        &creator.pubkey(),
+        &mint,
+        FEE_AMOUNT,
+    );
+    send(&mut context, &[post], &[&creator]).await.unwrap();
+    let bond: ListingBond = record(&mut context, &bond_account).await;
+    assert_eq!(bond.amount, FEE_AMOUNT);
+    let bonded = balance(&mut context, &bond_account).await;
+
+    let reclaim = client::reclaim_bond(&program_id, &GLOBAL_TENANT, &creator.pubkey(), &mint);
+    let err = send(&mut context, std::slice::from_ref(&reclaim), &[&creator])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::BondLocked {
+                unlocks_at: bond.posted_at + 3_600,
+            }
+        )
+    );
+    assert_eq!(balance(&mut context, &bond_account).await, bonded);
+
+    advance_clock(&mut context, 3_600).await;
+    let before = balance(&mut context, &creator.pubkey()).await;
+    send(&mut context, std::slice::from_ref(&reclaim), &[&creator])
+        .await
+        .unwrap();
+    assert!(account(&mut context, &bond_account).await.is_none());
+    assert_eq!(
+        balance(&mut context, &creator.pubkey()).await,
+        before + bonded
+    );
+
+    let err = send(&mut context, &[reclaim], &[&creator])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::BondReleased));
+}
+
+#[tokio::test]
+async fn reclaim_bond_waits_for_a_pending_challenge() {
+    let admin = Keypair::new();
+    let creator = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.verification_dispute_delay = 3_600;
+    });
+    program_test.add_account(creator.pubkey(), system_account(LAMPORTS));
+    let mint = add_info(&mut program_test, &program_id, &creator.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let challenger = context.payer.pubkey();
+    let info_account = info_address(&program_id, &mint);
+    let bond_account = find_listing_bond_account(&info_account, &program_id).0;
+
+    let post = client::post_listing_bond(
+        &program_id,
+        &GLOBAL_TENANT,
+        &creator.pubkey(),
+        &mint,
+        FEE_AMOUNT,
+    );
+    let verify = client::set_verified_batch(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &[info_account],
+        vec![true],
+    );
+    let challenge = client::challenge_verification(&program_id, &GLOBAL_TENANT, &challenger, &mint);
+    send(
+        &mut context,
+        &[post, verify, challenge],
+        &[&creator, &admin],
+    )
+    .await
+    .unwrap();
+
+    let reclaim = client::reclaim_bond(&program_id, &GLOBAL_TENANT, &creator.pubkey(), &mint);
+    let err = send(&mut context, std::slice::from_ref(&reclaim), &[&creator])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::ChallengePending));
+    let bond: ListingBond = record(&mut context, &bond_account).await;
+    assert_eq!(bond.amount, FEE_AMOUNT);
+
+    let resolve = client::resolve_challenge(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &mint,
+        &challenger,
+        &FEE_RECEIVER,
+        true,
+    );
+    send(&mut context, &[resolve, reclaim], &[&admin, &creator])
+        .await
+        .unwrap();
+    assert!(account(&mut context, &bond_account).await.is_none());
+    assert!(!info_record(&mut context, &info_account).await.verified);
+}
+
+#[tokio::test]
+async fn writable_accounts_must_be_distinct() {
+    let admin = Keypair::new();
+    let fee_receiver = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.fee_receiver = fee_receiver.pubkey();
+    });
+    program_test.add_account(fee_receiver.pubkey(), system_account(LAMPORTS));
+    let mint = add_info(&mut program_test, &program_id, &Pubkey::new_unique());
+    let receipt =
+        find_receipt_account(&GLOBAL_TENANT, &fee_receiver.pubkey(), &mint, &program_id).0;
+    program_test.add_account(
+        receipt,
+        receipt_account(&program_id, &fee_receiver.pubkey(), &mint),
+    );
+    let mut context = program_test.start_with_context().await;
+    let info_account = info_address(&program_id, &mint);
+
+    let batch = client::set_verified_batch(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &[info_account, info_account],
+        vec![true, false],
+    );
+    let err = send(&mut context, &[batch], &[&admin]).await.unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::DuplicateAccount));
+    assert!(!info_record(&mut context, &info_account).await.verified);
+
+    // A fee receiver refunding a create it paid for itself.
+    let refund = client::refund_receipt(
+        &program_id,
+        &GLOBAL_TENANT,
+        &admin.pubkey(),
+        &fee_receiver.pubkey(),
+        &fee_receiver.pubkey(),
+        &mint,
+    );
+    let err = send(&mut context, &[refund], &[&admin, &fee_receiver])
+        .await
+        .unwrap_err();
+    assert_eq!(err, program_error(0, TokenInfoError::DuplicateAccount));
+    let stored: Receipt = record(&mut context, &receipt).await;
+    assert_eq!(stored.refunded, 0);
+    assert_eq!(
+        balance(&mut context, &fee_receiver.pubkey()).await,
+        LAMPORTS
+    );
+}
+
+#[tokio::test]
+async fn operator_creates_split_the_fee_with_the_payout() {
+    let admin = Keypair::new();
+    let operator = Keypair::new();
+    let payout = Pubkey::new_unique();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let set_operator = |share_bps| {
+        client::set_operator(
+            &program_id,
+            &GLOBAL_TENANT,
+            &admin.pubkey(),
+            &operator.pubkey(),
+            &payout,
+            share_bps,
+        )
+    };
+    let err = send(&mut context, &[set_operator(10_001)], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::InvalidOperatorShare { share_bps: 10_001 }
+        )
+    );
+    let operator_account = find_operator_account(&GLOBAL_TENANT, &operator.pubkey(), &program_id).0;
+    assert!(account(&mut context, &operator_account).await.is_none());
+
+    send(&mut context, &[set_operator(2_000)], &[&admin])
+        .await
+        .unwrap();
+    let entry: Operator = record(&mut context, &operator_account).await;
+    assert_eq!(entry.payout, payout);
+    assert_eq!(entry.share_bps, 2_000);
+
+    let mut args = sample_args("A token");
+    args.origin = Some(operator.pubkey());
+    let create = Instruction::new_with_borsh(
+        program_id,
+        &ProgramInstruction::CreateInfoV2(args),
+        CreateInfoAccounts {
+            tenant: GLOBAL_TENANT,
+            payer,
+            creator: payer,
+            mint,
+            authority: admin.pubkey(),
+            fee_receiver: FEE_RECEIVER,
+            prepaid: false,
+            price_update: None,
+            origin: Some(operator.pubkey()),
+            operator_payout: Some(payout),
+        }
+        .to_account_metas(&program_id),
+    );
+    send(&mut context, &[create], &[&admin, &operator])
+        .await
+        .unwrap();
+
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.origin, Some(operator.pubkey()));
+    assert_eq!(balance(&mut context, &payout).await, FEE_AMOUNT / 5);
+    assert_eq!(
+        balance(&mut context, &FEE_RECEIVER).await,
+        FEE_AMOUNT - FEE_AMOUNT / 5
+    );
+}
+
+#[tokio::test]
+async fn validate_config_previews_the_violations_a_setter_refuses() {
+    let admin = Keypair::new();
+    let (program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mut context = program_test.start_with_context().await;
+    let unordered = vec![
+        LoyaltyTier {
+            min_paid_creations: 10,
+            discount_bps: 1_000,
+        },
+        LoyaltyTier {
+            min_paid_creations: 5,
+            discount_bps: 500,
+        },
+    ];
+    let violations = |processed: Processed| {
+        let return_data = processed.return_data.expect("violations are returned");
+        Vec::<ConfigViolation>::try_from_slice(&return_data.data).unwrap()
+    };
+
+    let current = client::validate_config(&program_id, &GLOBAL_TENANT, None);
+    assert!(violations(process(&mut context, &[current], &[]).await).is_empty());
+    let dry_run = client::validate_config(
+        &program_id,
+        &GLOBAL_TENANT,
+        Some(AdminAction::SetLoyaltyTiers {
+            tiers: unordered.clone(),
+        }),
+    );
+    assert_eq!(
+        violations(process(&mut context, &[dry_run], &[]).await),
+        [ConfigViolation::UnorderedLoyaltyTiers]
+    );
+
+    let set_tiers =
+        client::set_loyalty_tiers(&program_id, &GLOBAL_TENANT, &admin.pubkey(), unordered);
+    let err = send(&mut context, &[set_tiers], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::InvalidConfig { violations: 1 })
+    );
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.loyalty_tiers, Config::default_loyalty_tiers());
+}
+
+#[tokio::test]
+async fn versioned_instructions_need_a_recent_enough_program() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let get_version = client::get_api_version(&program_id);
+    let return_data = process(&mut context, &[get_version], &[])
+        .await
+        .return_data
+        .expect("the version is returned");
+    assert_eq!(
+        u32::try_from_slice(&return_data.data).unwrap(),
+        PROGRAM_API_VERSION
+    );
+
+    let create = |min_api_version| {
+        client::versioned(
+            min_api_version,
+            create_info_v2(
+                &program_id,
+                &payer,
+                &admin.pubkey(),
+                &mint,
+                sample_args("A token"),
+            ),
+        )
+    };
+    let err = send(&mut context, &[create(PROGRAM_API_VERSION + 1)], &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        program_error(
+            0,
+            TokenInfoError::UnsupportedApiVersion {
+                required: PROGRAM_API_VERSION + 1,
+                deployed: PROGRAM_API_VERSION,
+            }
+        )
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+
+    send(&mut context, &[create(PROGRAM_API_VERSION)], &[&admin])
+        .await
+        .unwrap();
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.mint, mint);
+}
+
+fn create_info_legacy(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    authority: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    client::create_info(
+        program_id,
+        &GLOBAL_TENANT,
+        payer,
+        mint,
+        &FEE_RECEIVER,
+        authority,
+        "A legacy token".to_string(),
+        sample_links(),
+        "https://example.com/icon.png".to_string(),
+        "https://example.com/header.png".to_string(),
+    )
+}
+
+#[tokio::test]
+async fn legacy_create_info_keeps_its_six_account_layout() {
+    let (mut program_test, program_id) = program_with_config(&AUTHORITY, |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let mut instruction = create_info_legacy(&program_id, &payer, &AUTHORITY, &mint);
+    instruction.accounts.truncate(6);
+    simulate(&mut context, &[instruction]).await.unwrap();
+}
+
+#[tokio::test]
+async fn legacy_create_info_records_the_payer_as_creator() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_legacy(&program_id, &payer, &admin.pubkey(), &mint);
+    send(&mut context, &[instruction], &[&admin]).await.unwrap();
+
+    let info = info_record(&mut context, &info_address(&program_id, &mint)).await;
+    assert_eq!(info.creator, payer);
+    assert_eq!(info.payer, payer);
+    assert_eq!(info.description_format, DescriptionFormat::PlainText);
+    assert_eq!(balance(&mut context, &FEE_RECEIVER).await, FEE_AMOUNT);
+    let config: Config = record(&mut context, &config_address(&program_id)).await;
+    assert_eq!(config.total_creates, 1);
+}
+
+#[tokio::test]
+async fn legacy_create_info_stops_at_the_cutover_when_the_config_is_passed() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |config| {
+        config.v1_cutover_slot = Some(0);
+    });
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let instruction = create_info_legacy(&program_id, &payer, &admin.pubkey(), &mint);
+    let err = send(&mut context, &[instruction], &[&admin])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        program_error(0, TokenInfoError::DeprecatedInstruction { cutover_slot: 0 })
+    );
+    assert!(
+        account(&mut context, &info_address(&program_id, &mint))
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn stats_count_creates_per_tenant_and_per_mint() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let get_mint_stats = || client::get_mint_stats(&program_id, &GLOBAL_TENANT, &mint);
+    let mint_stats = |processed: Processed| {
+        let return_data = processed.return_data.expect("the stats are returned");
+        MintStats::try_from_slice(&return_data.data).unwrap()
+    };
+    let before = mint_stats(process(&mut context, &[get_mint_stats()], &[]).await);
+    assert_eq!(before.creates, 0);
+    assert_eq!(before.mint, Pubkey::default());
+
+    let create = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    send(&mut context, &[create], &[&admin]).await.unwrap();
+
+    let after = mint_stats(process(&mut context, &[get_mint_stats()], &[]).await);
+    assert_eq!(after.creates, 1);
+    assert_eq!(after.mint, mint);
+    assert!(!after.verified);
+
+    let get_stats = client::get_stats(&program_id, &GLOBAL_TENANT);
+    let return_data = process(&mut context, &[get_stats], &[])
+        .await
+        .return_data
+        .expect("the stats are returned");
+    let stats = Stats::try_from_slice(&return_data.data).unwrap();
+    assert_eq!(stats.tenant, GLOBAL_TENANT);
+    assert_eq!(stats.total_creates, 1);
+    assert_eq!(stats.sponsored_creates, 0);
+}
+
+#[tokio::test]
+async fn growing_updates_eat_into_the_reserved_bytes() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+    let info_account = info_address(&program_id, &mint);
+
+    let create = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    send(&mut context, &[create], &[&admin]).await.unwrap();
+    let size = account(&mut context, &info_account)
+        .await
+        .unwrap()
+        .data
+        .len();
+    assert_eq!(
+        info_record(&mut context, &info_account)
+            .await
+            .reserved_bytes,
+        64
+    );
+
+    let timeline = LaunchTimeline {
+        launch_ts: Some(3_000),
+        ..LaunchTimeline::default()
+    };
+    let update = client::update_timeline(&program_id, &GLOBAL_TENANT, &payer, &mint, timeline);
+    send(&mut context, &[update], &[]).await.unwrap();
+
+    // `launch_ts` going from `None` to `Some` adds eight bytes, taken from
+    // the headroom without growing the account.
+    let info = info_record(&mut context, &info_account).await;
+    assert_eq!(info.reserved_bytes, 56);
+    assert_eq!(
+        account(&mut context, &info_account)
+            .await
+            .unwrap()
+            .data
+            .len(),
+        size
+    );
+}
+
+#[tokio::test]
+async fn trim_info_account_drops_the_headroom_and_reports_the_rewrite() {
+    let admin = Keypair::new();
+    let (mut program_test, program_id) = program_with_config(&admin.pubkey(), |_| {});
+    let mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+    let info_account = info_address(&program_id, &mint);
+
+    let create = create_info_v2(
+        &program_id,
+        &payer,
+        &admin.pubkey(),
+        &mint,
+        sample_args("A token"),
+    );
+    send(&mut context, &[create], &[&admin]).await.unwrap();
+    let before = info_record(&mut context, &info_account).await;
+    let size = account(&mut context, &info_account)
+        .await
+        .unwrap()
+        .data
+        .len();
+
+    let trim = client::trim_info_account(&program_id, &GLOBAL_TENANT, &payer, &mint);
+    let logged = process(&mut context, &[trim], &[]).await.events;
+
+    let after = info_record(&mut context, &info_account).await;
+    assert_eq!(after.reserved_bytes, 0);
+    let trimmed = account(&mut context, &info_account).await.unwrap();
+    assert_eq!(trimmed.data.len(), size - 64);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(trimmed.lamports, rent.minimum_balance(trimmed.data.len()));
+
+    let [
+        Event::InfoUpdated {
+            mint: updated_mint,
+            changed_fields,
+            old_hash,
+            new_hash,
+        },
+    ] = logged.as_slice()
+    else {
+        panic!("expected one InfoUpdated event, got {logged:?}");
+    };
+    let body_hash =
+        |info: TokenInfoV2| hash(&borsh::to_vec(&TokenInfo::V2(info)).unwrap()).to_bytes();
+    assert_eq!(*updated_mint, mint);
+    assert_eq!(*changed_fields, 0);
+    assert_eq!(*old_hash, body_hash(before));
+    assert_eq!(*new_hash, body_hash(after));
+}